@@ -0,0 +1,320 @@
+use core::{
+    any::Any,
+    fmt::{self, Debug, Display},
+};
+
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use crate::Error;
+
+/// Structured values that are attached to an error,
+/// such as the path of a file that failed to parse
+/// or the number of retries that have been attempted.
+///
+/// Attachments come in two flavors:
+///
+/// - _Typed values_ of arbitrary `Debug + Send + Sync + 'static` types, which
+///   can be retrieved by their type via [`get`](Self::get). Typed values are
+///   not printed.
+/// - _Fields_, i.e. key/value pairs of `Display`able values, which can be
+///   retrieved by their key via [`field`](Self::field). Fields are printed when
+///   the error is pretty-printed (`{:#}`), indented like the source location of
+///   the error.
+///
+/// Attachments can be added to [`AdHocError`]s, [`WrappedError`]s,
+/// and [`StashedErrors`] (usually via [`Error::attach`] or
+/// [`Attach::attach`]) as well as to errors that are pushed
+/// into an [`ErrorStash`] via [`push_with_attachments`]:
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// use lazy_errors::prelude::*;
+///
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// use lazy_errors::surrogate_error_trait::prelude::*;
+///
+/// use lazy_errors::Attachments;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct RequestId(u64);
+///
+/// fn parse(path: &str, line: u32) -> Result<(), Error> {
+///     Err(err!("Unexpected token"))
+///         .attach_field("path", path)
+///         .attach_field("line", line)
+///         .attach(RequestId(42))
+/// }
+///
+/// let mut errs = ErrorStash::new(|| "Failed to load config");
+/// let _ = parse("config.toml", 7).or_stash(&mut errs);
+/// errs.push_with_attachments(
+///     "Connection refused",
+///     Attachments::new().with_field("retries", 3),
+/// );
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let printed = format!("{err:#}");
+/// let printed = replace_line_numbers(&printed);
+/// assert_eq!(printed, indoc::indoc! {"
+///     Failed to load config
+///     - Unexpected token
+///       path: config.toml
+///       line: 7
+///       at src/attachments.rs:1234:56
+///       at src/attachments.rs:1234:56
+///     - Connection refused
+///       retries: 3
+///       at src/attachments.rs:1234:56"});
+///
+/// let err = parse("config.toml", 7).unwrap_err();
+/// let err = match err.as_ref() {
+///     ErrorData::AdHoc(err) => err,
+///     _ => unreachable!(),
+/// };
+/// let attachments = err.attachments();
+/// assert_eq!(attachments.get::<RequestId>(), Some(&RequestId(42)));
+/// assert_eq!(attachments.field("path"), Some("config.toml"));
+/// assert_eq!(attachments.field("line"), Some("7"));
+/// ```
+///
+/// [`AdHocError`]: crate::AdHocError
+/// [`WrappedError`]: crate::WrappedError
+/// [`StashedErrors`]: crate::StashedErrors
+/// [`ErrorStash`]: crate::ErrorStash
+/// [`push_with_attachments`]: crate::ErrorStash::push_with_attachments
+#[derive(Debug, Default)]
+pub struct Attachments {
+    values: Vec<Box<dyn Value>>,
+    fields: Vec<(Box<str>, Box<str>)>,
+}
+
+/// A typed value in [`Attachments`].
+trait Value: Any + Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any + Debug + Send + Sync> Value for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Adds the [`attach`](Self::attach) and
+/// [`attach_field`](Self::attach_field) methods on `Result<_, Error<I>>`.
+///
+/// Do not implement this trait.
+/// Importing the trait is sufficient due to blanket implementations.
+pub trait Attach<T, I> {
+    /// If `self` is `Result::Err(err)`, attaches a typed value to `err`
+    /// as described in [`Error::attach`].
+    ///
+    /// Please take a look at [`Attachments`] for an example.
+    fn attach<V>(self, value: V) -> Result<T, Error<I>>
+    where
+        V: Any + Debug + Send + Sync;
+
+    /// If `self` is `Result::Err(err)`, attaches a key/value pair to `err`
+    /// as described in [`Error::attach_field`].
+    ///
+    /// Please take a look at [`Attachments`] for an example.
+    fn attach_field<K, V>(self, key: K, value: V) -> Result<T, Error<I>>
+    where
+        K: Display,
+        V: Display;
+}
+
+impl<T, I> Attach<T, I> for Result<T, Error<I>> {
+    fn attach<V>(self, value: V) -> Result<T, Error<I>>
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.map_err(|err| err.attach(value))
+    }
+
+    fn attach_field<K, V>(self, key: K, value: V) -> Result<T, Error<I>>
+    where
+        K: Display,
+        V: Display,
+    {
+        self.map_err(|err| err.attach_field(key, value))
+    }
+}
+
+impl Attachments {
+    /// Creates an empty list of attachments, which does not allocate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a typed value and returns `self`.
+    pub fn with<V>(mut self, value: V) -> Self
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.insert(value);
+        self
+    }
+
+    /// Adds a key/value pair and returns `self`.
+    pub fn with_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Display,
+        V: Display,
+    {
+        self.insert_field(key, value);
+        self
+    }
+
+    /// Adds a typed value.
+    pub fn insert<V>(&mut self, value: V)
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.values.push(Box::new(value));
+    }
+
+    /// Adds a key/value pair.
+    /// Both the key and the value are converted to strings immediately.
+    pub fn insert_field<K, V>(&mut self, key: K, value: V)
+    where
+        K: Display,
+        V: Display,
+    {
+        let key = key.to_string().into_boxed_str();
+        let value = value.to_string().into_boxed_str();
+        self.fields.push((key, value));
+    }
+
+    /// Returns the first typed value of type `V`, if any.
+    pub fn get<V: Any>(&self) -> Option<&V> {
+        self.get_all().next()
+    }
+
+    /// Returns all typed values of type `V`
+    /// in the order in which they were added.
+    pub fn get_all<V: Any>(&self) -> impl Iterator<Item = &V> {
+        self.values
+            .iter()
+            .filter_map(|value| (**value).as_any().downcast_ref())
+    }
+
+    /// Returns the value of the first field with the given key, if any.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Returns all key/value pairs in the order in which they were added.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+
+    /// Returns `true` if there are neither typed values nor fields.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.fields.is_empty()
+    }
+
+    /// Prints each field on a separate line, indented by `indent`.
+    pub(crate) fn display_fields<W: fmt::Write + ?Sized>(
+        &self,
+        f: &mut W,
+        indent: &str,
+    ) -> fmt::Result {
+        for (key, value) in self.fields() {
+            writeln!(f)?;
+            write!(f, "{indent}{key}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use crate::doctest_line_num_helper as replace_line_numbers;
+
+    use super::Attachments;
+
+    #[test]
+    fn typed_values_are_retrieved_by_type() {
+        let attachments = Attachments::new()
+            .with(1_u8)
+            .with("text")
+            .with(2_u8);
+
+        assert_eq!(attachments.get::<u8>(), Some(&1));
+        assert_eq!(attachments.get::<&str>(), Some(&"text"));
+        assert_eq!(attachments.get::<u16>(), None);
+
+        let all: alloc::vec::Vec<_> = attachments.get_all::<u8>().collect();
+        assert_eq!(all, [&1, &2]);
+    }
+
+    #[test]
+    fn fields_are_retrieved_by_key() {
+        let attachments = Attachments::new()
+            .with_field("path", "a.txt")
+            .with_field("line", 42)
+            .with_field("path", "b.txt");
+
+        assert_eq!(attachments.field("path"), Some("a.txt"));
+        assert_eq!(attachments.field("line"), Some("42"));
+        assert_eq!(attachments.field("column"), None);
+        assert_eq!(attachments.fields().count(), 3);
+    }
+
+    #[test]
+    fn empty_attachments_are_empty() {
+        assert!(Attachments::new().is_empty());
+        assert!(!Attachments::new().with(()).is_empty());
+        assert!(!Attachments::new()
+            .with_field("key", "value")
+            .is_empty());
+    }
+
+    #[test]
+    fn fields_are_printed_in_pretty_format_only() {
+        let err: Error = Error::wrap_with("Inner", "Context")
+            .attach_field("user", "alice")
+            .attach(7_u32);
+
+        assert_eq!(format!("{err}"), "Context: Inner");
+
+        let printed = format!("{err:#}");
+        let printed = replace_line_numbers(&printed);
+        assert_eq!(
+            printed,
+            "Context: Inner\nuser: alice\nat src/attachments.rs:1234:56"
+        );
+
+        assert_eq!(err.attachments().get::<u32>(), Some(&7));
+    }
+
+    #[test]
+    fn fields_of_stash_are_printed_after_summary() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("Error");
+        let err: Error = errs
+            .into_result()
+            .attach_field("attempt", 2)
+            .unwrap_err();
+
+        let printed = format!("{err:#}");
+        let printed = replace_line_numbers(&printed);
+        assert_eq!(
+            printed,
+            "Summary\nattempt: 2\n- Error\n  at src/attachments.rs:1234:56"
+        );
+    }
+}
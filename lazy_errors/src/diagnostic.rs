@@ -0,0 +1,329 @@
+use core::fmt::{self, Display, Write};
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    ErrorData, ErrorLocation, InnerError, Node, NodeKind, Severity,
+    StashedErrors, Walk,
+};
+
+/// Iterator over the leaves of the entire tree of an [`Error`],
+/// yielding each leaf as [`Diagnostic`].
+///
+/// Values of this type are created by [`ErrorData::diagnostics`],
+/// which you can call on [`Error`] as well.
+/// The tree is traversed depth-first like [`Walk`] does,
+/// but only [`AdHocError`]s and inner errors that are not
+/// (or could not be identified as) errors from this crate are yielded.
+/// Each [`Diagnostic`] knows the source location of the leaf,
+/// its [`Severity`], and the summaries and contexts of its ancestors.
+///
+/// [`Diagnostic`] implements [`Display`] to print the leaf
+/// in the style of compiler diagnostics, i.e. `file:line:col: error: msg`.
+/// Most editors and CI log parsers understand that format,
+/// for example vim's quickfix list or VS Code's problem matchers.
+/// The summaries and contexts of the ancestors are printed
+/// as trailing note:
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// use lazy_errors::prelude::*;
+///
+/// let mut inner = ErrorStash::new(|| "Failed to parse config");
+/// inner.push("Unexpected token");
+/// inner.push_warning(Error::from_message("Key 'colour' is deprecated"));
+/// let inner: Error = inner.into_result().unwrap_err();
+///
+/// let mut errs = ErrorStash::new(|| "Failed to start");
+/// errs.push(Error::wrap_with(inner, "In 'config.toml'"));
+/// errs.push("Port already in use");
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let mut lines = vec![];
+/// for diagnostic in err.diagnostics() {
+///     lines.push(diagnostic.to_string());
+/// }
+///
+/// let lines = replace_line_numbers(&lines.join("\n"));
+/// assert_eq!(lines, indoc::indoc! {"
+///     src/diagnostic.rs:1234:56: error: Unexpected token \
+///     (note: Failed to start: In 'config.toml': Failed to parse config)
+///     src/diagnostic.rs:1234:56: warning: Key 'colour' is deprecated \
+///     (note: Failed to start: In 'config.toml': Failed to parse config)
+///     src/diagnostic.rs:1234:56: error: Port already in use \
+///     (note: Failed to start)"});
+/// # }
+/// ```
+///
+/// [`Error`]: crate::Error
+/// [`AdHocError`]: crate::AdHocError
+#[derive(Debug)]
+pub struct Diagnostics<'a, I> {
+    walk: Walk<'a, I>,
+    ancestors: Vec<Ancestor<'a, I>>,
+}
+
+/// A leaf in the tree of an [`Error`], as yielded by [`Diagnostics`].
+///
+/// [`Error`]: crate::Error
+#[derive(Debug)]
+pub struct Diagnostic<'a, I> {
+    node:     NodeKind<'a, I>,
+    location: Option<&'a ErrorLocation>,
+    severity: Severity,
+    context:  Vec<&'a str>,
+}
+
+/// A node on the path from the root to the current node of [`Diagnostics`].
+#[derive(Debug)]
+struct Ancestor<'a, I> {
+    context:  Option<&'a str>,
+    stash:    Option<&'a StashedErrors<I>>,
+    severity: Severity,
+}
+
+impl<'a, I: InnerError> Diagnostics<'a, I> {
+    pub(crate) fn new(root: &'a ErrorData<I>) -> Self {
+        Self {
+            walk: Walk::new(root),
+            ancestors: Vec::new(),
+        }
+    }
+
+    /// Returns the severity of the `node` that was just yielded by the walk,
+    /// assuming that the ancestors of that node are on the stack.
+    ///
+    /// If any error on the path to `node` was stashed as warning,
+    /// the entire subtree is considered to be a warning.
+    /// The same applies to [`AdHocError`]s that are warnings themselves.
+    ///
+    /// [`AdHocError`]: crate::AdHocError
+    fn severity_of(&self, node: &Node<'a, I>) -> Severity {
+        let inherited = match self.ancestors.last() {
+            None => Severity::Error,
+            Some(parent) => match (parent.stash, node.path().last()) {
+                (Some(errs), Some(&index)) => {
+                    Ord::min(parent.severity, errs.severity(index))
+                }
+                _ => parent.severity,
+            },
+        };
+
+        match node.kind() {
+            NodeKind::AdHoc(err) => Ord::min(inherited, err.severity()),
+            _ => inherited,
+        }
+    }
+}
+
+impl<'a, I: InnerError> Iterator for Diagnostics<'a, I> {
+    type Item = Diagnostic<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.walk.next()?;
+            self.ancestors.truncate(node.depth());
+            let severity = self.severity_of(&node);
+
+            let ancestor = match node.kind() {
+                NodeKind::Stashed(errs) => Ancestor {
+                    context: Some(errs.summary()),
+                    stash: Some(errs),
+                    severity,
+                },
+                NodeKind::Wrapped(err) => Ancestor {
+                    context: err.context(),
+                    stash: None,
+                    severity,
+                },
+                NodeKind::AdHoc(_) | NodeKind::Leaf(_) => {
+                    let context = self
+                        .ancestors
+                        .iter()
+                        .filter_map(|ancestor| ancestor.context)
+                        .collect();
+
+                    return Some(Diagnostic {
+                        node: node.kind(),
+                        location: node.location(),
+                        severity,
+                        context,
+                    });
+                }
+            };
+
+            self.ancestors.push(ancestor);
+        }
+    }
+}
+
+impl<'a, I> Diagnostic<'a, I> {
+    /// Returns the leaf error,
+    /// which is either a [`NodeKind::AdHoc`] or a [`NodeKind::Leaf`].
+    pub fn node(&self) -> NodeKind<'a, I> {
+        self.node
+    }
+
+    /// Returns the source location of the leaf error, if known.
+    /// Please take a look at [`Node::location`] for details.
+    pub fn location(&self) -> Option<&'a ErrorLocation> {
+        self.location
+    }
+
+    /// Returns [`Severity::Warning`] if the leaf error, or any error
+    /// on the path from the root to the leaf error, was stashed as warning.
+    /// Returns [`Severity::Error`] otherwise.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the summaries of [`StashedErrors`] and the contexts
+    /// of [`WrappedError`](crate::WrappedError)s
+    /// on the path from the root to the leaf error, starting at the root.
+    pub fn context(&self) -> &[&'a str] {
+        &self.context
+    }
+
+    /// Returns the message of the leaf error.
+    pub fn message(&self) -> String
+    where
+        I: Display,
+    {
+        let mut message = String::new();
+        let _ = self.display_message(&mut message);
+        message
+    }
+
+    pub(crate) fn display_message<W>(&self, f: &mut W) -> fmt::Result
+    where
+        I: Display,
+        W: Write + ?Sized,
+    {
+        match self.node {
+            NodeKind::AdHoc(err) => write!(f, "{}", err.message()),
+            NodeKind::Leaf(err) => write!(f, "{err}"),
+            NodeKind::Stashed(errs) => write!(f, "{errs}"),
+            NodeKind::Wrapped(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<I: Display> Display for Diagnostic<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = self.location {
+            write!(f, "{location}: ")?;
+        }
+
+        write!(f, "{}: ", self.severity)?;
+        self.display_message(f)?;
+
+        if let Some((first, rest)) = self.context.split_first() {
+            write!(f, " (note: {first}")?;
+            for context in rest {
+                write!(f, ": {context}")?;
+            }
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use crate::Severity;
+
+    #[test]
+    fn ad_hoc_error_yields_single_diagnostic() {
+        let err: Error = Error::from_message("Oops");
+        let diagnostics: Vec<_> = err.diagnostics().collect();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message(), "Oops");
+        assert_eq!(diagnostics[0].severity(), Severity::Error);
+        assert!(diagnostics[0].context().is_empty());
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn ad_hoc_warning_yields_warning_diagnostic() {
+        let warning: Error = crate::warn!("Deprecated");
+        let error: Error = crate::err!("Oops");
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push(warning);
+        errs.push(error);
+        let err: Error = errs.into_result().unwrap_err();
+
+        let severities: Vec<_> = err
+            .diagnostics()
+            .map(|d| d.severity())
+            .collect();
+
+        assert_eq!(severities, [Severity::Warning, Severity::Error]);
+    }
+
+    #[test]
+    fn diagnostics_print_location_severity_and_context() {
+        use crate::doctest_line_num_helper as replace_line_numbers;
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push_warning(Error::from_message("Deprecated"));
+        let err: Error = errs
+            .into_result_with_warnings()
+            .unwrap()
+            .unwrap();
+
+        let printed: Vec<_> = err
+            .diagnostics()
+            .map(|d| replace_line_numbers(&d.to_string()))
+            .collect();
+
+        assert_eq!(printed, [
+            "src/diagnostic.rs:1234:56: warning: Deprecated (note: Summary)"
+        ]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn warning_severity_is_inherited_by_subtree() {
+        let mut inner = ErrorStash::new(|| "Inner");
+        inner.push("First");
+        inner.push("Second");
+        let inner: Error = inner.into_result().unwrap_err();
+
+        let mut errs = ErrorStash::new(|| "Outer");
+        errs.push_warning(Error::wrap_with(inner, "Context"));
+        errs.push("Third");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let diagnostics: Vec<_> = err
+            .diagnostics()
+            .map(|d| (d.message(), d.severity(), d.context().to_vec()))
+            .collect();
+
+        assert_eq!(diagnostics, [
+            (
+                "First".to_string(),
+                Severity::Warning,
+                ["Outer", "Context", "Inner"].to_vec(),
+            ),
+            (
+                "Second".to_string(),
+                Severity::Warning,
+                ["Outer", "Context", "Inner"].to_vec(),
+            ),
+            ("Third".to_string(), Severity::Error, ["Outer"].to_vec()),
+        ]);
+    }
+}
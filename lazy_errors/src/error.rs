@@ -1,10 +1,40 @@
 use core::{
+    any::Any,
     fmt::{self, Debug, Display},
     ops::Deref,
 };
 
-use alloc::{boxed::Box, format, string::ToString};
+#[cfg(feature = "rust-v1.81")]
+use core::error::Error as StdError;
+
+#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
+use std::error::Error as StdError;
+
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+#[cfg(feature = "tracing")]
+use tracing_error::SpanTrace;
+
+#[cfg(feature = "tracing")]
+use crate::trace::display_span_trace;
+
+use alloc::{boxed::Box, format, string::ToString, vec::Vec};
+
+use crate::{
+    kind::downcast_kind,
+    retention::{display_omitted, Omitted},
+    Attachments, Diagnostics, DowncastInner, ErrorKind, ErrorLocation,
+    GithubAnnotations, InnerError, Severity, Walk,
+};
 
+/// The source location at which an error was created, wrapped, or stashed.
+///
+/// `lazy_errors` records source locations implicitly via `#[track_caller]`.
+/// The errors in the tree of an [`Error`] store their locations as
+/// [`ErrorLocation`], which can also hold the original source location
+/// of an error that has been reconstructed from an
+/// [`OwnedError`](crate::OwnedError).
 pub type Location = &'static core::panic::Location<'static>;
 
 /// The primary error type to use when using this crate.
@@ -68,6 +98,12 @@ pub type Location = &'static core::panic::Location<'static>;
 /// `core::error::Error + Send + Sync + 'static` as well,
 /// so it can be consumed by other crates
 /// that support errors in general.
+/// Errors that were wrapped or stashed are returned from
+/// `core::error::Error::source`, so such crates can walk
+/// the chain of error sources as usual.
+/// Since `source` can return at most one error,
+/// a list of stashed errors will return its _first_ error as source.
+/// You can use `ErrorData::sources` to access all of them.
 ///
 /// In Rust versions before v1.81, `core::error::Error` is not stable.
 /// If you don't enable the `std` feature in that case, `lazy_errors`
@@ -99,6 +135,8 @@ pub type Location = &'static core::panic::Location<'static>;
 ///
 /// Note that you won't be able to print or debug-print errors
 /// if the inner error type does not implement [`Display`]/[`Debug`].
+/// Furthermore, `Error<I>` only implements `core::error::Error`
+/// if `I` implements [`InnerError`] as well.
 /// On the other hand, such error types are completely unsupported by `eyre`:
 ///
 /// ```compile_fail
@@ -151,6 +189,7 @@ pub type Location = &'static core::panic::Location<'static>;
 /// ```
 ///
 /// [1]: https://github.com/dtolnay/anyhow/issues/81
+/// [`InnerError`]: crate::InnerError
 /// [`Reportable`]: crate::Reportable
 /// [`surrogate_error_trait::prelude`]: crate::surrogate_error_trait::prelude
 /// [`surrogate_error_trait::prelude::Stashable`]:
@@ -218,7 +257,111 @@ pub struct StashedErrors<I> {
     errors: Box<[I]>,
 
     /// Guaranteed to contain one element dedicated to each `errors` entry.
-    locations: Box<[Location]>,
+    locations: Box<[ErrorLocation]>,
+
+    /// Optional data of the `errors` entries at the same index.
+    /// May be shorter than `errors` (or empty) if no error
+    /// has been added with a kind, attachments, backtrace, etc.
+    extras: Box<[Extras]>,
+
+    /// The kind of the list itself, if any.
+    kind: Option<Box<dyn ErrorKind>>,
+
+    /// The attachments of the list itself.
+    attachments: Attachments,
+
+    /// Counts the errors that have been dropped from the stash
+    /// because of its [`Retention`](crate::Retention).
+    omitted: Omitted,
+
+    /// Whether the limit of the [`FailFast`](crate::FailFast) policy
+    /// of the stash has been reached.
+    aborted: bool,
+}
+
+/// All parts of a [`StashedErrors`] list,
+/// as returned by [`StashedErrors::into_parts`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct StashedParts<I> {
+    /// Summarizes all errors in the list.
+    pub summary: Box<str>,
+
+    /// The errors in the list, along with the data
+    /// that has been recorded when they were added to the list.
+    pub entries: Vec<StashedEntry<I>>,
+
+    /// The kind of the list itself, if any.
+    pub kind: Option<Box<dyn ErrorKind>>,
+
+    /// The attachments of the list itself.
+    pub attachments: Attachments,
+
+    /// The number of errors (of [`Severity::Error`]) that have been
+    /// dropped from the stash because of its [`Retention`](crate::Retention).
+    pub omitted_errors: usize,
+
+    /// The number of warnings that have been dropped from the stash
+    /// because of its [`Retention`](crate::Retention).
+    pub omitted_warnings: usize,
+
+    /// Whether the limit of the [`FailFast`](crate::FailFast) policy
+    /// of the stash has been reached.
+    pub aborted: bool,
+}
+
+/// An error in [`StashedParts::entries`], along with the data
+/// that has been recorded when it was added to the list.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct StashedEntry<I> {
+    pub error: I,
+    pub location: ErrorLocation,
+    pub kind: Option<Box<dyn ErrorKind>>,
+    pub attachments: Attachments,
+    pub severity: Severity,
+
+    /// Only set if a backtrace has been captured.
+    #[cfg(feature = "backtrace")]
+    pub backtrace: Option<Backtrace>,
+
+    /// Only set if a span trace has been captured.
+    #[cfg(feature = "tracing")]
+    pub span_trace: Option<SpanTrace>,
+}
+
+/// Optional data that has been recorded along with an error
+/// when it was added to a [`StashedErrors`] or
+/// [`StashWithErrors`](crate::StashWithErrors) list.
+///
+/// Missing entries are equal to [`Extras::default`],
+/// so lists store [`Extras`] only once they're actually needed.
+#[derive(Debug, Default)]
+pub(crate) struct Extras {
+    pub(crate) kind: Option<Box<dyn ErrorKind>>,
+    pub(crate) attachments: Attachments,
+    pub(crate) severity: Severity,
+
+    /// Only set if a backtrace has actually been captured.
+    #[cfg(feature = "backtrace")]
+    pub(crate) backtrace: Option<Backtrace>,
+
+    /// Only set if a span trace has actually been captured.
+    #[cfg(feature = "tracing")]
+    pub(crate) span_trace: Option<SpanTrace>,
+}
+
+/// A note that is printed after the list of [`StashedErrors`],
+/// informing users that the list is incomplete.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Note {
+    /// Errors or warnings have been dropped because of the
+    /// [`Retention`](crate::Retention) of the stash.
+    Omitted(Omitted),
+
+    /// Processing has been aborted early because of the
+    /// [`FailFast`](crate::FailFast) policy of the stash.
+    Aborted,
 }
 
 /// Wraps exactly one (custom or third-party) error, along with
@@ -274,9 +417,35 @@ pub struct StashedErrors<I> {
 /// [`or_wrap_with`]: crate::OrWrapWith::or_wrap_with
 #[derive(Debug)]
 pub struct WrappedError<I> {
-    context:  Option<Box<str>>,
-    inner:    I,
-    location: Location,
+    context: Option<Box<str>>,
+    inner: I,
+    location: ErrorLocation,
+    kind: Option<Box<dyn ErrorKind>>,
+    attachments: Attachments,
+
+    #[cfg(feature = "backtrace")]
+    backtrace: Backtrace,
+
+    #[cfg(feature = "tracing")]
+    span_trace: SpanTrace,
+}
+
+/// All parts of a [`WrappedError`],
+/// as returned by [`WrappedError::into_parts`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct WrappedParts<I> {
+    pub context: Option<Box<str>>,
+    pub inner: I,
+    pub location: ErrorLocation,
+    pub kind: Option<Box<dyn ErrorKind>>,
+    pub attachments: Attachments,
+
+    #[cfg(feature = "backtrace")]
+    pub backtrace: Backtrace,
+
+    #[cfg(feature = "tracing")]
+    pub span_trace: SpanTrace,
 }
 
 /// A single, “one of a kind” [`Error`], created from an ad-hoc error message,
@@ -286,8 +455,9 @@ pub struct WrappedError<I> {
 /// Most of the time this type is used only internally.
 ///
 /// Values of this type get created internally
-/// when the [`err!`](crate::err!) macro or
-/// when [`Error::from_message`] are called.
+/// when the [`err!`](crate::err!) or [`warn!`](crate::warn!) macros or
+/// when [`Error::from_message`] or [`Error::from_warning_message`]
+/// are called.
 ///
 /// `AdHocError` can be printed and supports “pretty-printing” as well:
 ///
@@ -308,8 +478,29 @@ pub struct WrappedError<I> {
 /// ```
 #[derive(Debug)]
 pub struct AdHocError {
-    message:  Box<str>,
-    location: Location,
+    message: Box<str>,
+    location: ErrorLocation,
+    severity: Severity,
+    kind: Option<Box<dyn ErrorKind>>,
+    attachments: Attachments,
+
+    #[cfg(feature = "backtrace")]
+    backtrace: Backtrace,
+}
+
+/// All parts of an [`AdHocError`],
+/// as returned by [`AdHocError::into_parts`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct AdHocParts {
+    pub message: Box<str>,
+    pub location: ErrorLocation,
+    pub severity: Severity,
+    pub kind: Option<Box<dyn ErrorKind>>,
+    pub attachments: Attachments,
+
+    #[cfg(feature = "backtrace")]
+    pub backtrace: Backtrace,
 }
 
 impl<I> From<ErrorData<I>> for Error<I> {
@@ -338,35 +529,55 @@ impl<I> From<Error<I>> for ErrorData<I> {
     }
 }
 
-#[cfg(feature = "rust-v1.81")]
-impl<I: Display + Debug> core::error::Error for Error<I> {}
-
-#[cfg(feature = "rust-v1.81")]
-impl<I: Display + Debug> core::error::Error for ErrorData<I> {}
-
-#[cfg(feature = "rust-v1.81")]
-impl<I: Display + Debug> core::error::Error for StashedErrors<I> {}
-
-#[cfg(feature = "rust-v1.81")]
-impl<I: Display + Debug> core::error::Error for WrappedError<I> {}
-
-#[cfg(feature = "rust-v1.81")]
-impl core::error::Error for AdHocError {}
-
-#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
-impl<I: Display + Debug> std::error::Error for Error<I> {}
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<I> StdError for Error<I>
+where
+    I: Display + Debug + InnerError,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.deref().source()
+    }
+}
 
-#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
-impl<I: Display + Debug> std::error::Error for ErrorData<I> {}
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<I> StdError for ErrorData<I>
+where
+    I: Display + Debug + InnerError,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Stashed(errs) => errs.source(),
+            Self::Wrapped(err) => err.source(),
+            Self::AdHoc(err) => err.source(),
+        }
+    }
+}
 
-#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
-impl<I: Display + Debug> std::error::Error for StashedErrors<I> {}
+/// Since [`core::error::Error::source`] can return at most one error,
+/// [`StashedErrors`] will return its _first_ error as source.
+/// Use [`StashedErrors::sources`] to access all errors.
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<I> StdError for StashedErrors<I>
+where
+    I: Display + Debug + InnerError,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.sources().next()
+    }
+}
 
-#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
-impl<I: Display + Debug> std::error::Error for WrappedError<I> {}
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<I> StdError for WrappedError<I>
+where
+    I: Display + Debug + InnerError,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.inner.as_error_source()
+    }
+}
 
-#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
-impl std::error::Error for AdHocError {}
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl StdError for AdHocError {}
 
 impl<I: Display> Display for Error<I> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -488,34 +699,83 @@ impl<I: Display> Display for StashedErrors<I> {
         match (errors, locations, is_pretty) {
             ([], ..) => write!(f, "{summary}: 0 errors"),
             (_, [], ..) => write!(f, "{summary}: 0 source locations"),
-            ([e], _, false) => write!(f, "{summary}: {e}"),
+            ([e], _, false) => {
+                match self.severity(0) {
+                    Severity::Warning => write!(f, "{summary}: warning: {e}")?,
+                    Severity::Error => write!(f, "{summary}: {e}")?,
+                }
+                match self.has_notes() {
+                    false => Ok(()),
+                    true => {
+                        write!(f, " (")?;
+                        display_notes(f, self)?;
+                        write!(f, ")")
+                    }
+                }
+            }
             (errs, _, false) => {
-                write!(f, "{summary} ({} errors)", errs.len())
+                match self.warning_count() {
+                    0 => write!(f, "{summary} ({} errors", errs.len())?,
+                    w => {
+                        write!(f, "{summary} (")?;
+                        display_count(f, errs.len() - w, "error")?;
+                        write!(f, ", ")?;
+                        display_count(f, w, "warning")?;
+                    }
+                }
+                match self.has_notes() {
+                    false => write!(f, ")"),
+                    true => {
+                        write!(f, ", ")?;
+                        display_notes(f, self)?;
+                        write!(f, ")")
+                    }
+                }
             }
-            (errs, locs, true) => {
+            (_, _, true) => {
                 write!(f, "{summary}")?;
-                display_list_of_children(f, errs, locs)
+                self.attachments.display_fields(f, "")?;
+                display_list_of_children(f, self)?;
+                for note in self.notes() {
+                    write!(f, "\n- {note}")?;
+                }
+                Ok(())
             }
         }
     }
 }
 
+impl Display for Note {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Note::Omitted(omitted) => display_omitted(f, *omitted),
+            Note::Aborted => write!(f, "aborted early"),
+        }
+    }
+}
+
 impl<I: Display> Display for WrappedError<I> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let err = &self.inner;
-        let loc = self.location;
+        let loc = &self.location;
         let is_pretty = f.alternate(); // `#` in format string
 
         match (&self.context, is_pretty) {
             (None, false) => write!(f, "{err}"),
             (None, true) => {
                 write!(f, "{err:#}")?;
+                self.attachments.display_fields(f, "")?;
 
                 // Note that the error may have printed its location already
                 // in case it's an error type from our crate. In that case
                 // we'd end up with duplicate locations. This is fine
                 // as long as we're printing one location per line.
-                display_location(f, "", loc)
+                display_location(f, "", loc)?;
+                #[cfg(feature = "backtrace")]
+                display_backtrace(f, "", &self.backtrace)?;
+                #[cfg(feature = "tracing")]
+                display_span_trace(f, "", &self.span_trace)?;
+                Ok(())
             }
             (Some(context), false) => {
                 // Refer to the note about recursion depth in `StashedErrors`.
@@ -524,7 +784,13 @@ impl<I: Display> Display for WrappedError<I> {
             (Some(context), true) => {
                 // Refer to the note about recursion depth in `StashedErrors`.
                 write!(f, "{context}: {err:#}")?;
-                display_location(f, "", loc)
+                self.attachments.display_fields(f, "")?;
+                display_location(f, "", loc)?;
+                #[cfg(feature = "backtrace")]
+                display_backtrace(f, "", &self.backtrace)?;
+                #[cfg(feature = "tracing")]
+                display_span_trace(f, "", &self.span_trace)?;
+                Ok(())
             }
         }
     }
@@ -536,8 +802,12 @@ impl Display for AdHocError {
         if !is_pretty {
             write!(f, "{}", self.message)
         } else {
-            writeln!(f, "{}", self.message)?;
-            write!(f, "at {}", self.location)
+            write!(f, "{}", self.message)?;
+            self.attachments.display_fields(f, "")?;
+            display_location(f, "", &self.location)?;
+            #[cfg(feature = "backtrace")]
+            display_backtrace(f, "", &self.backtrace)?;
+            Ok(())
         }
     }
 }
@@ -549,6 +819,13 @@ impl<I> Error<I> {
         ErrorData::from_message(msg).into()
     }
 
+    /// Creates an [`AdHocError`] variant of [`Error`] from a message
+    /// and marks it as [`Severity::Warning`].
+    #[track_caller]
+    pub fn from_warning_message<M: Display>(msg: M) -> Self {
+        ErrorData::from_warning_message(msg).into()
+    }
+
     /// Creates a [`StashedErrors`] variant of [`Error`].
     pub fn from_stash<M, E, L>(summary: M, errors: E, locations: L) -> Self
     where
@@ -582,6 +859,50 @@ impl<I> Error<I> {
     {
         ErrorData::wrap_with(err, msg).into()
     }
+
+    /// Attaches a user-defined kind to this error,
+    /// replacing the kind that may have been attached before.
+    ///
+    /// The kind is attached to the root of the tree of this error,
+    /// i.e. to the [`AdHocError`], [`WrappedError`], or [`StashedErrors`]
+    /// that this [`Error`] holds.
+    /// Please take a look at [`ErrorKind`] for details and an example.
+    pub fn with_kind<K: ErrorKind>(mut self, kind: K) -> Self {
+        self.0.set_kind(Box::new(kind));
+        self
+    }
+
+    /// Attaches a typed value to this error.
+    ///
+    /// The value is attached to the root of the tree of this error,
+    /// i.e. to the [`AdHocError`], [`WrappedError`], or [`StashedErrors`]
+    /// that this [`Error`] holds.
+    /// Please take a look at [`Attachments`] for details and an example.
+    pub fn attach<V>(mut self, value: V) -> Self
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.0.attachments_mut().insert(value);
+        self
+    }
+
+    /// Attaches a key/value pair to this error,
+    /// which will be printed when the error is pretty-printed (`{:#}`).
+    ///
+    /// The field is attached to the root of the tree of this error,
+    /// i.e. to the [`AdHocError`], [`WrappedError`], or [`StashedErrors`]
+    /// that this [`Error`] holds.
+    /// Please take a look at [`Attachments`] for details and an example.
+    pub fn attach_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Display,
+        V: Display,
+    {
+        self.0
+            .attachments_mut()
+            .insert_field(key, value);
+        self
+    }
 }
 
 impl<I> ErrorData<I> {
@@ -592,6 +913,15 @@ impl<I> ErrorData<I> {
         Self::AdHoc(err)
     }
 
+    /// Creates an [`AdHocError`] variant of [`Error`] from a message
+    /// and marks it as [`Severity::Warning`].
+    #[track_caller]
+    pub fn from_warning_message<M: Display>(msg: M) -> Self {
+        let err = AdHocError::from_message(msg.to_string())
+            .with_severity(Severity::Warning);
+        Self::AdHoc(err)
+    }
+
     /// Creates a [`StashedErrors`] variant of [`Error`].
     pub fn from_stash<M, E, L>(summary: M, errors: E, locations: L) -> Self
     where
@@ -627,6 +957,64 @@ impl<I> ErrorData<I> {
         Self::Wrapped(WrappedError::wrap_with(err, msg))
     }
 
+    /// Attaches a user-defined kind to this error,
+    /// replacing the kind that may have been attached before.
+    ///
+    /// Please take a look at [`Error::with_kind`] for details.
+    pub fn with_kind<K: ErrorKind>(mut self, kind: K) -> Self {
+        self.set_kind(Box::new(kind));
+        self
+    }
+
+    /// Attaches a typed value to this error.
+    ///
+    /// Please take a look at [`Error::attach`] for details.
+    pub fn attach<V>(mut self, value: V) -> Self
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.attachments_mut().insert(value);
+        self
+    }
+
+    /// Attaches a key/value pair to this error.
+    ///
+    /// Please take a look at [`Error::attach_field`] for details.
+    pub fn attach_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Display,
+        V: Display,
+    {
+        self.attachments_mut()
+            .insert_field(key, value);
+        self
+    }
+
+    /// Returns the attachments of this error (but not of its children).
+    pub fn attachments(&self) -> &Attachments {
+        match self {
+            Self::Stashed(errs) => errs.attachments(),
+            Self::Wrapped(err) => err.attachments(),
+            Self::AdHoc(err) => err.attachments(),
+        }
+    }
+
+    fn attachments_mut(&mut self) -> &mut Attachments {
+        match self {
+            Self::Stashed(errs) => &mut errs.attachments,
+            Self::Wrapped(err) => &mut err.attachments,
+            Self::AdHoc(err) => &mut err.attachments,
+        }
+    }
+
+    fn set_kind(&mut self, kind: Box<dyn ErrorKind>) {
+        match self {
+            Self::Stashed(errs) => errs.kind = Some(kind),
+            Self::Wrapped(err) => err.kind = Some(kind),
+            Self::AdHoc(err) => err.kind = Some(kind),
+        }
+    }
+
     /// Deprecated method that was renamed to
     /// [`children`](Self::children).
     #[deprecated(since = "0.6.0", note = "renamed to `children`")]
@@ -680,116 +1068,1215 @@ impl<I> ErrorData<I> {
             Self::Stashed(errs) => errs.errors(),
         }
     }
-}
 
-impl<I> StashedErrors<I> {
-    pub fn from<M, E, L>(summary: M, errors: E, locations: L) -> Self
+    /// Returns an iterator that walks the entire tree of this error
+    /// depth-first, starting at this error, and yields each
+    /// [`Node`](crate::Node)
+    /// along with its depth, path, kind, and location.
+    ///
+    /// In contrast to [`children`](Self::children),
+    /// the iterator also descends into transitive children
+    /// if they are errors from this crate as well.
+    /// Please take a look at [`Walk`] for details and an example.
+    pub fn walk(&self) -> Walk<'_, I>
     where
-        M: Display,
-        E: Into<Box<[I]>>,
-        L: Into<Box<[Location]>>,
+        I: InnerError,
     {
-        Self {
-            summary:   summary.to_string().into_boxed_str(),
-            errors:    errors.into(),
-            locations: locations.into(),
-        }
+        Walk::new(self)
     }
 
-    pub fn errors(&self) -> &[I] {
-        &self.errors
+    /// Returns an iterator over the leaves of the entire tree of this error,
+    /// yielding each leaf as [`Diagnostic`](crate::Diagnostic),
+    /// which can be printed in the style of compiler diagnostics
+    /// (`file:line:col: error: msg`).
+    /// Please take a look at [`Diagnostics`] for details and an example.
+    pub fn diagnostics(&self) -> Diagnostics<'_, I>
+    where
+        I: InnerError,
+    {
+        Diagnostics::new(self)
     }
-}
 
-impl<I> WrappedError<I> {
-    /// Creates a [`WrappedError`]
-    /// from something that can be turned into an
-    /// [_inner error type_ `I`](Error#inner-error-type-i).
-    #[track_caller]
-    pub fn wrap<E>(err: E) -> Self
+    /// Returns a value that prints the leaves of the entire tree
+    /// of this error as GitHub Actions workflow commands
+    /// (`::error file=...,line=...,col=...,title=...::message`)
+    /// when formatted via [`Display`].
+    /// Please take a look at [`GithubAnnotations`] for details and an example.
+    pub fn github_annotations(&self) -> GithubAnnotations<'_, I> {
+        GithubAnnotations::new(self)
+    }
+
+    /// Returns a value that serializes the leaves of the entire tree
+    /// of this error as SARIF log, reporting them as results
+    /// of the tool called `tool_name`.
+    /// Please take a look at [`Sarif`](crate::Sarif) for details
+    /// and an example.
+    #[cfg(feature = "sarif")]
+    pub fn sarif<'a>(&'a self, tool_name: &'a str) -> crate::Sarif<'a, I> {
+        crate::Sarif::new(self, tool_name)
+    }
+
+    /// Records this error in the fields of `span`:
+    ///
+    /// - `error`: This error, printed in its short form (`{}`)
+    /// - `error.tree`: The entire tree of this error, pretty-printed (`{:#}`)
+    /// - `error.leaves`: The number of leaves in the tree of this error (see
+    ///   [`diagnostics`](Self::diagnostics))
+    ///
+    /// Since `tracing` only records fields that have been declared
+    /// when the span was created, you need to declare the fields
+    /// you're interested in, for example as `tracing::field::Empty`:
+    ///
+    /// ```
+    /// use lazy_errors::prelude::*;
+    ///
+    /// let span = tracing::info_span!(
+    ///     "run",
+    ///     error = tracing::field::Empty,
+    ///     error.leaves = tracing::field::Empty,
+    /// );
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to run");
+    /// errs.push("First error");
+    /// errs.push("Second error");
+    ///
+    /// let err: Error = errs.into_result().unwrap_err();
+    /// err.record_in_span(&span); // Records `error` and `error.leaves`
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn record_in_span(&self, span: &tracing::Span)
     where
-        E: Into<I>,
+        I: Display + InnerError,
     {
-        Self {
-            context:  None,
-            inner:    err.into(),
-            location: location(),
-        }
+        crate::trace::record_in_span(self, span)
     }
 
-    /// Creates a [`WrappedError`]
-    /// from something that can be turned into an
-    /// [_inner error type_ `I`](Error#inner-error-type-i)
-    /// and annotates it with an informative message.
-    #[track_caller]
-    pub fn wrap_with<E, M>(err: E, msg: M) -> Self
+    /// Returns a [`tracing::Value`] that records the entire tree
+    /// of this error, pretty-printed (`{:#}`),
+    /// for example as field of an event:
+    ///
+    /// ```
+    /// use lazy_errors::prelude::*;
+    ///
+    /// let err: Error = Error::from_message("Something went wrong");
+    /// tracing::error!(error = err.as_tracing_value(), "Failed to run");
+    /// ```
+    ///
+    /// Since [`tracing::Value`] is a sealed trait, it cannot be implemented
+    /// for the error types of this crate directly.
+    #[cfg(feature = "tracing")]
+    pub fn as_tracing_value(&self) -> impl tracing::Value + '_
     where
-        E: Into<I>,
-        M: Display,
+        I: Display,
     {
-        Self {
-            context:  Some(msg.to_string().into_boxed_str()),
-            inner:    err.into(),
-            location: location(),
-        }
+        crate::trace::as_value(self)
     }
 
-    /// Return the error that was wrapped.
-    pub fn inner(&self) -> &I {
-        &self.inner
+    /// Emits a `log` record at the given `level` for each leaf
+    /// in the tree of this error (see [`diagnostics`](Self::diagnostics)).
+    ///
+    /// Leaves that have been added as [`Severity::Warning`]
+    /// are logged at the `Warn` level at most.
+    /// The target of each record is derived from the source file
+    /// of the leaf, for example `src::parser` for `src/parser.rs`,
+    /// or is `lazy_errors` if the leaf has no source location.
+    /// The message of each record is the message of the leaf.
+    /// Each record has the following keys:
+    ///
+    /// - `context`: The summaries and contexts on the path from the root to the
+    ///   leaf, separated by `: `
+    /// - `severity`: The [`Severity`] of the leaf
+    /// - `location`: The source location of the leaf, if known
+    ///
+    /// ```
+    /// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// # {
+    /// use lazy_errors::prelude::*;
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to start");
+    /// errs.push("Config file not found");
+    /// errs.push_warning(Error::from_message("Cache is disabled"));
+    /// let err: Error = errs.into_result().unwrap_err();
+    ///
+    /// // Logs `Config file not found` at the `Error` level
+    /// // and `Cache is disabled` at the `Warn` level,
+    /// // both with `context=Failed to start`.
+    /// err.log(log::Level::Error);
+    /// # }
+    /// ```
+    ///
+    /// [`Severity`]: crate::Severity
+    /// [`Severity::Warning`]: crate::Severity::Warning
+    #[cfg(feature = "log")]
+    pub fn log(&self, level: log::Level)
+    where
+        I: Display + InnerError,
+    {
+        crate::logging::log(self, level)
     }
-}
 
-impl AdHocError {
-    /// Creates an [`AdHocError`] from a message.
-    #[track_caller]
-    pub fn from_message<M: Display>(msg: M) -> Self {
-        Self {
-            message:  msg.to_string().into_boxed_str(),
-            location: location(),
-        }
+    /// Returns the first error of type `T` in the entire tree of this error,
+    /// searching depth-first as described in [`walk`](Self::walk).
+    ///
+    /// ```
+    /// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// # {
+    /// use core::str::FromStr;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn parse(input: &str) -> Result<u8, Error> {
+    ///     u8::from_str(input).or_wrap_with(|| format!("Invalid: {input}"))
+    /// }
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to parse input");
+    /// let _ = parse("42").or_stash(&mut errs);
+    /// let _ = parse("❌").or_stash(&mut errs);
+    /// let err: Error = errs.into_result().unwrap_err();
+    ///
+    /// let e = err
+    ///     .find::<core::num::ParseIntError>()
+    ///     .unwrap();
+    /// assert_eq!(e.to_string(), "invalid digit found in string");
+    /// assert!(err
+    ///     .find::<core::num::TryFromIntError>()
+    ///     .is_none());
+    /// # }
+    /// ```
+    ///
+    /// Searching requires the
+    /// [_inner error type_ `I`](Error#inner-error-type-i)
+    /// to support downcasting to `T`, as declared by [`DowncastInner`].
+    /// This is the case for the `Stashable` type of the regular `prelude`
+    /// as well as for [`StashableAny`].
+    ///
+    /// [`DowncastInner`]: crate::DowncastInner
+    /// [`StashableAny`]: crate::surrogate_error_trait::StashableAny
+    pub fn find<T>(&self) -> Option<&T>
+    where
+        T: 'static,
+        I: DowncastInner<T> + 'static,
+    {
+        self.find_all().next()
     }
-}
-
-#[track_caller]
-pub fn location() -> Location {
-    core::panic::Location::caller()
-}
 
-fn display_list_of_children<I: Display>(
-    f: &mut fmt::Formatter<'_>,
-    errs: &[I],
-    locs: &[Location],
-) -> fmt::Result {
-    for (e, l) in errs.iter().zip(locs) {
-        display_multiline(f, &e)?;
-        display_location(f, "  ", l)?;
+    /// Returns all errors of type `T` in the entire tree of this error,
+    /// in depth-first order as described in [`walk`](Self::walk).
+    ///
+    /// ```
+    /// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// # {
+    /// use core::str::FromStr;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// let mut inner = ErrorStash::new(|| "Inner errors");
+    /// let _ = u8::from_str("256").or_stash(&mut inner);
+    /// let inner: Error = inner.into_result().unwrap_err();
+    ///
+    /// let mut errs = ErrorStash::new(|| "Outer errors");
+    /// errs.push(inner);
+    /// let _ = u8::from_str("").or_stash(&mut errs);
+    /// let err: Error = errs.into_result().unwrap_err();
+    ///
+    /// let found: Vec<String> = err
+    ///     .find_all::<core::num::ParseIntError>()
+    ///     .map(|e| e.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(found, &[
+    ///     "number too large to fit in target type",
+    ///     "cannot parse integer from empty string",
+    /// ]);
+    /// # }
+    /// ```
+    ///
+    /// Please take a look at [`find`](Self::find) for the trait bounds.
+    pub fn find_all<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: 'static,
+        I: DowncastInner<T> + 'static,
+    {
+        self.walk()
+            .filter_map(|node| node.downcast_ref::<T>())
     }
-    Ok(())
-}
 
-fn display_multiline<I: Display>(
-    f: &mut fmt::Formatter<'_>,
-    err: &I,
-) -> fmt::Result {
-    let mut prefix = "- ";
-    for line in format!("{err:#}").lines() {
-        writeln!(f)?;
-        write!(f, "{prefix}{line}")?;
-        prefix = "  ";
+    /// Returns `true` if any error of type `T`
+    /// in the entire tree of this error satisfies the predicate.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use lazy_errors::prelude::*;
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to read files");
+    /// errs.push(io::Error::from(ErrorKind::PermissionDenied));
+    /// errs.push(Error::wrap(io::Error::from(ErrorKind::NotFound)));
+    /// let err: Error = errs.into_result().unwrap_err();
+    ///
+    /// assert!(err.any(|e: &io::Error| e.kind() == ErrorKind::NotFound));
+    /// assert!(!err.any(|e: &io::Error| e.kind() == ErrorKind::TimedOut));
+    /// # }
+    /// ```
+    ///
+    /// Please take a look at [`find`](Self::find) for the trait bounds.
+    pub fn any<T, P>(&self, predicate: P) -> bool
+    where
+        T: 'static,
+        I: DowncastInner<T> + 'static,
+        P: FnMut(&T) -> bool,
+    {
+        self.find_all().any(predicate)
     }
-    Ok(())
-}
 
-fn display_location(
+    /// Returns the first kind of type `K` in the entire tree of this error,
+    /// searching depth-first as described in [`walk`](Self::walk).
+    ///
+    /// Please take a look at [`ErrorKind`] for an example.
+    pub fn find_kind<K: ErrorKind>(&self) -> Option<&K>
+    where
+        I: InnerError,
+    {
+        self.find_all_kinds().next()
+    }
+
+    /// Returns all kinds of type `K` in the entire tree of this error,
+    /// in depth-first order as described in [`walk`](Self::walk).
+    ///
+    /// If an error has been pushed into an [`ErrorStash`] along with a kind
+    /// and the error itself has a kind as well,
+    /// the kind of the error is returned first.
+    ///
+    /// [`ErrorStash`]: crate::ErrorStash
+    pub fn find_all_kinds<K: ErrorKind>(&self) -> impl Iterator<Item = &K>
+    where
+        I: InnerError,
+    {
+        self.walk()
+            .flat_map(|node| node.error_kinds())
+            .filter_map(downcast_kind)
+    }
+
+    /// Returns the greatest kind of type `K`
+    /// in the entire tree of this error.
+    ///
+    /// Implement [`Ord`] for `K` such that more severe kinds are greater
+    /// than less severe kinds.
+    /// Please take a look at [`ErrorKind`] for an example.
+    pub fn most_severe_kind<K>(&self) -> Option<&K>
+    where
+        K: ErrorKind + Ord,
+        I: InnerError,
+    {
+        self.find_all_kinds().max()
+    }
+
+    /// Returns all [`children`](Self::children) of this error
+    /// that can be viewed as `core::error::Error`,
+    /// as determined by [`InnerError::as_error_source`].
+    ///
+    /// In contrast to [`core::error::Error::source`],
+    /// which returns at most one error,
+    /// this method returns _all_ errors
+    /// if this error is a [`StashedErrors`] variant.
+    ///
+    /// ```
+    /// # #[cfg(feature = "rust-v1.81")]
+    /// # {
+    /// use core::error::Error as _;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// let mut errs = ErrorStash::new(|| "One or more things went wrong");
+    /// errs.push("An error");
+    /// errs.push("Another error");
+    ///
+    /// let r: Result<(), Error> = errs.into();
+    /// let err = r.unwrap_err();
+    ///
+    /// let sources: Vec<String> = err
+    ///     .sources()
+    ///     .map(|e| e.to_string())
+    ///     .collect();
+    /// assert_eq!(sources, &["An error", "Another error"]);
+    ///
+    /// let source = err.source().unwrap();
+    /// assert_eq!(source.to_string(), "An error");
+    /// # }
+    /// ```
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn StdError + 'static)>
+    where
+        I: InnerError,
+    {
+        self.children()
+            .iter()
+            .filter_map(InnerError::as_error_source)
+    }
+}
+
+impl<I> StashedErrors<I> {
+    pub fn from<M, E, L>(summary: M, errors: E, locations: L) -> Self
+    where
+        M: Display,
+        E: Into<Box<[I]>>,
+        L: Into<Box<[Location]>>,
+    {
+        let locations: Box<[ErrorLocation]> = locations
+            .into()
+            .iter()
+            .map(|&location| ErrorLocation::from(location))
+            .collect();
+
+        Self::from_error_locations(summary, errors, locations)
+    }
+
+    /// Creates a [`StashedErrors`] list like [`StashedErrors::from`],
+    /// but from source locations that may have been restored
+    /// from [`OwnedLocation`](crate::OwnedLocation)s.
+    pub(crate) fn from_error_locations<M, E, L>(
+        summary: M,
+        errors: E,
+        locations: L,
+    ) -> Self
+    where
+        M: Display,
+        E: Into<Box<[I]>>,
+        L: Into<Box<[ErrorLocation]>>,
+    {
+        Self {
+            summary: summary.to_string().into_boxed_str(),
+            errors: errors.into(),
+            locations: locations.into(),
+            extras: Box::new([]),
+            kind: None,
+            attachments: Attachments::new(),
+            omitted: Omitted::default(),
+            aborted: false,
+        }
+    }
+
+    /// Replaces the [`Extras`] of the errors in this list,
+    /// which may be shorter than the list of errors.
+    pub(crate) fn with_extras<X>(mut self, extras: X) -> Self
+    where
+        X: Into<Box<[Extras]>>,
+    {
+        self.extras = extras.into();
+        self
+    }
+
+    /// Replaces the number of errors that have been dropped
+    /// from the stash because of its [`Retention`](crate::Retention).
+    pub(crate) fn with_omitted(mut self, omitted: Omitted) -> Self {
+        self.omitted = omitted;
+        self
+    }
+
+    /// Replaces whether the limit of the [`FailFast`](crate::FailFast)
+    /// policy of the stash has been reached.
+    pub(crate) fn with_aborted(mut self, aborted: bool) -> Self {
+        self.aborted = aborted;
+        self
+    }
+
+    /// Attaches a user-defined kind to this list of errors,
+    /// replacing the kind that may have been attached before.
+    ///
+    /// Please take a look at [`Error::with_kind`] for details.
+    pub fn with_kind<K: ErrorKind>(mut self, kind: K) -> Self {
+        self.kind = Some(Box::new(kind));
+        self
+    }
+
+    /// Returns the kind that has been attached to this list of errors
+    /// if it is of type `K`.
+    ///
+    /// Note that this method does not return the kinds of the errors
+    /// in this list. Please take a look at
+    /// [`ErrorData::find_kind`] if you want to search them as well.
+    pub fn kind<K: ErrorKind>(&self) -> Option<&K> {
+        self.kind
+            .as_deref()
+            .and_then(downcast_kind)
+    }
+
+    pub(crate) fn dyn_kind(&self) -> Option<&dyn ErrorKind> {
+        self.kind.as_deref()
+    }
+
+    /// Attaches a typed value to this error.
+    ///
+    /// Please take a look at [`Error::attach`] for details.
+    pub fn attach<V>(mut self, value: V) -> Self
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.attachments.insert(value);
+        self
+    }
+
+    /// Attaches a key/value pair to this error.
+    ///
+    /// Please take a look at [`Error::attach_field`] for details.
+    pub fn attach_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Display,
+        V: Display,
+    {
+        self.attachments
+            .insert_field(key, value);
+        self
+    }
+
+    /// Returns the attachments of this list of errors.
+    ///
+    /// Note that this method does not return the attachments of the errors
+    /// in this list. Please take a look at
+    /// [`entry_attachments`](Self::entry_attachments) instead.
+    pub fn attachments(&self) -> &Attachments {
+        &self.attachments
+    }
+
+    /// Returns the attachments that have been attached to the error
+    /// at `index` when it was added to this list, if any.
+    pub fn entry_attachments(&self, index: usize) -> Option<&Attachments> {
+        self.extras
+            .get(index)
+            .map(|extras| &extras.attachments)
+    }
+
+    /// Returns the [`Severity`] with which the error at `index`
+    /// was added to this list.
+    ///
+    /// Returns [`Severity::Error`] if `index` is out of bounds.
+    pub fn severity(&self, index: usize) -> Severity {
+        self.extras
+            .get(index)
+            .map(|extras| extras.severity)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of errors in this list
+    /// that have been added as [`Severity::Warning`].
+    pub fn warning_count(&self) -> usize {
+        self.extras
+            .iter()
+            .filter(|extras| extras.severity.is_warning())
+            .count()
+    }
+
+    /// Returns `true` if at least one error in this list
+    /// has been added as [`Severity::Error`].
+    ///
+    /// Errors that have been dropped from the stash
+    /// because of its [`Retention`](crate::Retention)
+    /// are taken into account as well.
+    pub fn has_errors(&self) -> bool {
+        self.warning_count() < self.errors.len() || self.omitted.errors > 0
+    }
+
+    /// Returns the number of errors that have been added to the stash
+    /// but have not been retained because of its
+    /// [`Retention`](crate::Retention).
+    /// These errors are not part of [`errors`](Self::errors).
+    pub fn omitted_count(&self) -> usize {
+        self.omitted.count()
+    }
+
+    /// Returns the number of errors and warnings that have been dropped
+    /// from the stash because of its [`Retention`](crate::Retention).
+    pub(crate) fn omitted(&self) -> Omitted {
+        self.omitted
+    }
+
+    /// Returns `true` if processing has been aborted early
+    /// because the limit of the [`FailFast`](crate::FailFast) policy
+    /// of the stash has been reached.
+    /// In that case, the list of errors is likely incomplete.
+    pub fn is_aborted(&self) -> bool {
+        self.aborted
+    }
+
+    /// Returns the notes to print after the list of errors.
+    pub(crate) fn notes(&self) -> impl Iterator<Item = Note> {
+        let omitted = match self.omitted.count() {
+            0 => None,
+            _ => Some(Note::Omitted(self.omitted)),
+        };
+
+        let aborted = match self.aborted {
+            true => Some(Note::Aborted),
+            false => None,
+        };
+
+        omitted.into_iter().chain(aborted)
+    }
+
+    /// Returns `true` if any notes will be printed
+    /// after the list of errors.
+    pub(crate) fn has_notes(&self) -> bool {
+        self.notes().next().is_some()
+    }
+
+    /// Returns the kind that has been attached to the error at `index`
+    /// when it was added to this list, if any.
+    pub(crate) fn entry_kind(&self, index: usize) -> Option<&dyn ErrorKind> {
+        self.extras
+            .get(index)
+            .and_then(|extras| extras.kind.as_deref())
+    }
+
+    /// Returns the message that summarizes all errors in this list.
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn errors(&self) -> &[I] {
+        &self.errors
+    }
+
+    /// Returns the source locations at which the [`errors`](Self::errors)
+    /// were added to the list, e.g. when they were pushed into an
+    /// [`ErrorStash`](crate::ErrorStash).
+    /// Each location belongs to the error at the same index.
+    ///
+    /// If this list has been reconstructed from an
+    /// [`OwnedError`](crate::OwnedError), these are the original
+    /// source locations.
+    pub fn locations(&self) -> &[ErrorLocation] {
+        &self.locations
+    }
+
+    /// Returns the backtrace that was captured when the error at `index`
+    /// was added to the list, if any.
+    ///
+    /// Backtraces are only captured if enabled by the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables
+    /// (see [`Backtrace::capture`]).
+    #[cfg(feature = "backtrace")]
+    pub fn entry_backtrace(&self, index: usize) -> Option<&Backtrace> {
+        self.extras
+            .get(index)
+            .and_then(|extras| extras.backtrace.as_ref())
+    }
+
+    /// Returns the span trace that was captured when the error at `index`
+    /// was added to the list, if any.
+    ///
+    /// Span traces are only captured if a `tracing_error::ErrorLayer`
+    /// is installed (see [`SpanTrace::capture`]).
+    #[cfg(feature = "tracing")]
+    pub fn entry_span_trace(&self, index: usize) -> Option<&SpanTrace> {
+        self.extras
+            .get(index)
+            .and_then(|extras| extras.span_trace.as_ref())
+    }
+
+    /// Returns pairs of each error in this list and the source location
+    /// at which the error was added to the list.
+    pub fn errors_with_locations(
+        &self,
+    ) -> impl Iterator<Item = (&I, &ErrorLocation)> {
+        self.errors
+            .iter()
+            .zip(self.locations.iter())
+    }
+
+    /// Deconstructs this list into all of its parts, i.e.
+    /// the summary message, the errors along with all data that has been
+    /// recorded when they were added to the list,
+    /// and the data of the list itself.
+    ///
+    /// ```
+    /// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// use lazy_errors::prelude::*;
+    ///
+    /// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    /// use lazy_errors::surrogate_error_trait::prelude::*;
+    ///
+    /// use lazy_errors::Severity;
+    ///
+    /// let mut errs = ErrorStash::new(|| "Summary");
+    /// errs.push("First error");
+    /// errs.push_warning("Second error");
+    ///
+    /// let err: Error = errs.into_result().unwrap_err();
+    /// let errs = match ErrorData::from(err) {
+    ///     ErrorData::Stashed(errs) => errs,
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// let parts = errs.into_parts();
+    /// assert_eq!(&*parts.summary, "Summary");
+    /// assert_eq!(parts.entries.len(), 2);
+    /// assert_eq!(parts.entries[0].error.to_string(), "First error");
+    /// assert_eq!(parts.entries[0].severity, Severity::Error);
+    /// assert_eq!(parts.entries[1].severity, Severity::Warning);
+    /// assert_eq!(parts.entries[1].location.file(), file!());
+    /// assert!(!parts.aborted);
+    /// ```
+    pub fn into_parts(self) -> StashedParts<I> {
+        let mut extras = self.extras.into_vec().into_iter();
+        let entries = self
+            .errors
+            .into_vec()
+            .into_iter()
+            .zip(self.locations.into_vec())
+            .map(|(error, location)| {
+                let extras = extras.next().unwrap_or_default();
+                StashedEntry {
+                    error,
+                    location,
+                    kind: extras.kind,
+                    attachments: extras.attachments,
+                    severity: extras.severity,
+                    #[cfg(feature = "backtrace")]
+                    backtrace: extras.backtrace,
+                    #[cfg(feature = "tracing")]
+                    span_trace: extras.span_trace,
+                }
+            })
+            .collect();
+
+        StashedParts {
+            summary: self.summary,
+            entries,
+            kind: self.kind,
+            attachments: self.attachments,
+            omitted_errors: self.omitted.errors,
+            omitted_warnings: self.omitted.warnings,
+            aborted: self.aborted,
+        }
+    }
+
+    /// Deconstructs this list into pairs of each error
+    /// and the source location at which the error was added to the list.
+    ///
+    /// All other data is dropped, i.e. the summary message,
+    /// the kinds, attachments, severities, backtraces, and span traces
+    /// of the errors, as well as the data of the list itself.
+    /// Please use [`into_parts`](Self::into_parts) to keep that data.
+    ///
+    /// This method can be used to move errors into another container:
+    ///
+    /// ```
+    /// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// use lazy_errors::prelude::*;
+    ///
+    /// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    /// use lazy_errors::surrogate_error_trait::prelude::*;
+    ///
+    /// let mut errs = ErrorStash::new(|| "Summary");
+    /// errs.push("First error");
+    /// errs.push("Second error");
+    ///
+    /// let err: Error = errs.into_result().unwrap_err();
+    /// let errs = match ErrorData::from(err) {
+    ///     ErrorData::Stashed(errs) => errs,
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// let mut other = ErrorStash::new(|| "Other summary");
+    /// for (e, location) in errs.into_errors_with_locations() {
+    ///     assert_eq!(location.file(), file!());
+    ///     other.push(e);
+    /// }
+    ///
+    /// assert_eq!(other.errors().len(), 2);
+    /// ```
+    pub fn into_errors_with_locations(
+        self,
+    ) -> impl Iterator<Item = (I, ErrorLocation)> {
+        self.errors
+            .into_vec()
+            .into_iter()
+            .zip(self.locations.into_vec())
+    }
+
+    /// Returns all [`errors`](Self::errors) in this list
+    /// that can be viewed as `core::error::Error`,
+    /// as determined by [`InnerError::as_error_source`].
+    ///
+    /// Note that [`core::error::Error::source`] will only return
+    /// the first of these errors.
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn StdError + 'static)>
+    where
+        I: InnerError,
+    {
+        self.errors
+            .iter()
+            .filter_map(InnerError::as_error_source)
+    }
+}
+
+impl<I> WrappedError<I> {
+    /// Creates a [`WrappedError`]
+    /// from something that can be turned into an
+    /// [_inner error type_ `I`](Error#inner-error-type-i).
+    #[track_caller]
+    pub fn wrap<E>(err: E) -> Self
+    where
+        E: Into<I>,
+    {
+        Self {
+            context: None,
+            inner: err.into(),
+            location: location().into(),
+            kind: None,
+            attachments: Attachments::new(),
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::capture(),
+            #[cfg(feature = "tracing")]
+            span_trace: SpanTrace::capture(),
+        }
+    }
+
+    /// Creates a [`WrappedError`]
+    /// from something that can be turned into an
+    /// [_inner error type_ `I`](Error#inner-error-type-i)
+    /// and annotates it with an informative message.
+    #[track_caller]
+    pub fn wrap_with<E, M>(err: E, msg: M) -> Self
+    where
+        E: Into<I>,
+        M: Display,
+    {
+        Self {
+            context: Some(msg.to_string().into_boxed_str()),
+            inner: err.into(),
+            location: location().into(),
+            kind: None,
+            attachments: Attachments::new(),
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::capture(),
+            #[cfg(feature = "tracing")]
+            span_trace: SpanTrace::capture(),
+        }
+    }
+
+    /// Return the error that was wrapped.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// Returns the message that annotates the wrapped error, if any.
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// Returns the source location at which the error was wrapped.
+    ///
+    /// If this error has been reconstructed from an
+    /// [`OwnedError`](crate::OwnedError), this is the original
+    /// source location.
+    pub fn location(&self) -> &ErrorLocation {
+        &self.location
+    }
+
+    /// Attaches a user-defined kind to this error,
+    /// replacing the kind that may have been attached before.
+    ///
+    /// Please take a look at [`Error::with_kind`] for details.
+    pub fn with_kind<K: ErrorKind>(mut self, kind: K) -> Self {
+        self.kind = Some(Box::new(kind));
+        self
+    }
+
+    /// Returns the kind that has been attached to this error
+    /// if it is of type `K`.
+    pub fn kind<K: ErrorKind>(&self) -> Option<&K> {
+        self.kind
+            .as_deref()
+            .and_then(downcast_kind)
+    }
+
+    pub(crate) fn dyn_kind(&self) -> Option<&dyn ErrorKind> {
+        self.kind.as_deref()
+    }
+
+    /// Attaches a typed value to this error.
+    ///
+    /// Please take a look at [`Error::attach`] for details.
+    pub fn attach<V>(mut self, value: V) -> Self
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.attachments.insert(value);
+        self
+    }
+
+    /// Attaches a key/value pair to this error.
+    ///
+    /// Please take a look at [`Error::attach_field`] for details.
+    pub fn attach_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Display,
+        V: Display,
+    {
+        self.attachments
+            .insert_field(key, value);
+        self
+    }
+
+    /// Returns the attachments of this error.
+    pub fn attachments(&self) -> &Attachments {
+        &self.attachments
+    }
+
+    /// Returns the backtrace that was captured when the error was wrapped.
+    ///
+    /// Backtraces are only captured if enabled by the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables
+    /// (see [`Backtrace::capture`]).
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Returns the span trace that was captured when the error was wrapped.
+    ///
+    /// Span traces are only captured if a `tracing_error::ErrorLayer`
+    /// is installed (see [`SpanTrace::capture`]).
+    #[cfg(feature = "tracing")]
+    pub fn span_trace(&self) -> &SpanTrace {
+        &self.span_trace
+    }
+
+    /// Returns the error that was wrapped,
+    /// dropping the context message, the source location,
+    /// and all other data of this error.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Creates a [`WrappedError`] from the optional context message,
+    /// the error that was wrapped,
+    /// and the source location at which the error was wrapped.
+    ///
+    /// The error will have neither a kind nor attachments.
+    /// No backtrace or span trace will be captured.
+    pub fn from_parts<L>(
+        context: Option<Box<str>>,
+        inner: I,
+        location: L,
+    ) -> Self
+    where
+        L: Into<ErrorLocation>,
+    {
+        Self {
+            context,
+            inner,
+            location: location.into(),
+            kind: None,
+            attachments: Attachments::new(),
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::disabled(),
+            #[cfg(feature = "tracing")]
+            span_trace: SpanTrace::new(tracing::Span::none()),
+        }
+    }
+
+    /// Deconstructs this error into all of its parts, i.e.
+    /// the optional context message, the error that was wrapped,
+    /// the source location, and all other data of this error.
+    ///
+    /// ```
+    /// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// use lazy_errors::prelude::*;
+    ///
+    /// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    /// use lazy_errors::surrogate_error_trait::prelude::*;
+    ///
+    /// let err = WrappedError::wrap_with("Inner error", "Context")
+    ///     .attach_field("key", "value");
+    /// let parts = err.into_parts();
+    ///
+    /// assert_eq!(parts.context.as_deref(), Some("Context"));
+    /// assert_eq!(parts.inner.to_string(), "Inner error");
+    /// assert_eq!(parts.location.file(), file!());
+    /// assert_eq!(parts.attachments.field("key"), Some("value"));
+    /// ```
+    pub fn into_parts(self) -> WrappedParts<I> {
+        WrappedParts {
+            context: self.context,
+            inner: self.inner,
+            location: self.location,
+            kind: self.kind,
+            attachments: self.attachments,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+            #[cfg(feature = "tracing")]
+            span_trace: self.span_trace,
+        }
+    }
+}
+
+impl AdHocError {
+    /// Creates an [`AdHocError`] from a message.
+    #[track_caller]
+    pub fn from_message<M: Display>(msg: M) -> Self {
+        Self {
+            message: msg.to_string().into_boxed_str(),
+            location: location().into(),
+            severity: Severity::Error,
+            kind: None,
+            attachments: Attachments::new(),
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates an [`AdHocError`] from a message and the source location
+    /// at which the error was created.
+    ///
+    /// The error will have [`Severity::Error`]
+    /// and neither a kind nor attachments.
+    /// No backtrace will be captured.
+    pub fn from_parts<L>(message: Box<str>, location: L) -> Self
+    where
+        L: Into<ErrorLocation>,
+    {
+        Self {
+            message,
+            location: location.into(),
+            severity: Severity::Error,
+            kind: None,
+            attachments: Attachments::new(),
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::disabled(),
+        }
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the source location at which the error was created.
+    ///
+    /// If this error has been reconstructed from an
+    /// [`OwnedError`](crate::OwnedError), this is the original
+    /// source location.
+    pub fn location(&self) -> &ErrorLocation {
+        &self.location
+    }
+
+    /// Sets the [`Severity`] of this error.
+    ///
+    /// Errors created by the [`warn!`](crate::warn!) macro
+    /// have [`Severity::Warning`], all other errors have
+    /// [`Severity::Error`] unless set otherwise.
+    /// [`Diagnostics`](crate::Diagnostics) report an error as warning
+    /// if the error itself or any of the errors it is nested in
+    /// is a warning.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Returns the [`Severity`] of this error.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Attaches a user-defined kind to this error,
+    /// replacing the kind that may have been attached before.
+    ///
+    /// Please take a look at [`Error::with_kind`] for details.
+    pub fn with_kind<K: ErrorKind>(mut self, kind: K) -> Self {
+        self.kind = Some(Box::new(kind));
+        self
+    }
+
+    /// Returns the kind that has been attached to this error
+    /// if it is of type `K`.
+    pub fn kind<K: ErrorKind>(&self) -> Option<&K> {
+        self.kind
+            .as_deref()
+            .and_then(downcast_kind)
+    }
+
+    pub(crate) fn dyn_kind(&self) -> Option<&dyn ErrorKind> {
+        self.kind.as_deref()
+    }
+
+    /// Attaches a typed value to this error.
+    ///
+    /// Please take a look at [`Error::attach`] for details.
+    pub fn attach<V>(mut self, value: V) -> Self
+    where
+        V: Any + Debug + Send + Sync,
+    {
+        self.attachments.insert(value);
+        self
+    }
+
+    /// Attaches a key/value pair to this error.
+    ///
+    /// Please take a look at [`Error::attach_field`] for details.
+    pub fn attach_field<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Display,
+        V: Display,
+    {
+        self.attachments
+            .insert_field(key, value);
+        self
+    }
+
+    /// Returns the attachments of this error.
+    pub fn attachments(&self) -> &Attachments {
+        &self.attachments
+    }
+
+    /// Returns the backtrace that was captured when the error was created.
+    ///
+    /// Backtraces are only captured if enabled by the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables
+    /// (see [`Backtrace::capture`]).
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Deconstructs this error into all of its parts, i.e.
+    /// the message, the source location, and all other data of this error.
+    pub fn into_parts(self) -> AdHocParts {
+        AdHocParts {
+            message: self.message,
+            location: self.location,
+            severity: self.severity,
+            kind: self.kind,
+            attachments: self.attachments,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+        }
+    }
+}
+
+impl Extras {
+    /// Returns `true` if these [`Extras`] are equal to [`Extras::default`],
+    /// i.e. if they don't need to be stored.
+    pub(crate) fn is_empty(&self) -> bool {
+        #[cfg(feature = "backtrace")]
+        if self.backtrace.is_some() {
+            return false;
+        }
+
+        #[cfg(feature = "tracing")]
+        if self.span_trace.is_some() {
+            return false;
+        }
+
+        self.kind.is_none()
+            && self.attachments.is_empty()
+            && !self.severity.is_warning()
+    }
+}
+
+#[track_caller]
+pub fn location() -> Location {
+    core::panic::Location::caller()
+}
+
+fn display_list_of_children<I: Display>(
     f: &mut fmt::Formatter<'_>,
+    errs: &StashedErrors<I>,
+) -> fmt::Result {
+    for (i, (e, l)) in errs.errors_with_locations().enumerate() {
+        let prefix = match errs.severity(i) {
+            Severity::Warning => "- warning: ",
+            Severity::Error => "- ",
+        };
+
+        display_multiline(f, prefix, &e)?;
+        if let Some(attachments) = errs.entry_attachments(i) {
+            attachments.display_fields(f, "  ")?;
+        }
+        display_location(f, "  ", l)?;
+
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = errs.entry_backtrace(i) {
+            display_backtrace(f, "  ", backtrace)?;
+        }
+
+        #[cfg(feature = "tracing")]
+        if let Some(span_trace) = errs.entry_span_trace(i) {
+            display_span_trace(f, "  ", span_trace)?;
+        }
+    }
+    Ok(())
+}
+
+fn display_multiline<I: Display>(
+    f: &mut fmt::Formatter<'_>,
+    first_line_prefix: &str,
+    err: &I,
+) -> fmt::Result {
+    let mut prefix = first_line_prefix;
+    for line in format!("{err:#}").lines() {
+        writeln!(f)?;
+        write!(f, "{prefix}{line}")?;
+        prefix = "  ";
+    }
+    Ok(())
+}
+
+/// Prints the notes of `errs`, separated by `, `.
+fn display_notes<I>(
+    f: &mut fmt::Formatter<'_>,
+    errs: &StashedErrors<I>,
+) -> fmt::Result {
+    for (i, note) in errs.notes().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{note}")?;
+    }
+    Ok(())
+}
+
+/// Prints `count` followed by `noun` in singular or plural form.
+fn display_count(
+    f: &mut fmt::Formatter<'_>,
+    count: usize,
+    noun: &str,
+) -> fmt::Result {
+    match count {
+        1 => write!(f, "1 {noun}"),
+        n => write!(f, "{n} {noun}s"),
+    }
+}
+
+pub(crate) fn display_location<W: fmt::Write + ?Sized>(
+    f: &mut W,
     indent: &str,
-    location: Location,
+    location: &ErrorLocation,
 ) -> fmt::Result {
     writeln!(f)?;
     write!(f, "{indent}at {location}")
 }
 
+/// Prints the backtrace, unless it has not been captured.
+#[cfg(feature = "backtrace")]
+pub(crate) fn display_backtrace<W: fmt::Write + ?Sized>(
+    f: &mut W,
+    indent: &str,
+    backtrace: &Backtrace,
+) -> fmt::Result {
+    if backtrace.status() != BacktraceStatus::Captured {
+        return Ok(());
+    }
+
+    writeln!(f)?;
+    write!(f, "{indent}stack backtrace:")?;
+    for line in backtrace.to_string().lines() {
+        writeln!(f)?;
+        write!(f, "{indent}{line}")?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -805,6 +2292,245 @@ mod tests {
         assert_small::<super::Error<Stashable>>();
     }
 
+    #[test]
+    #[cfg(feature = "rust-v1.81")]
+    fn source_of_wrapped_error_is_inner_error() {
+        use alloc::string::ToString;
+        use core::error::Error as _;
+
+        use crate::prelude::*;
+
+        let err: Error = Error::wrap_with("Inner error", "Outer error");
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "Inner error");
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "rust-v1.81")]
+    fn source_of_stashed_errors_is_first_error() {
+        use alloc::{string::ToString, vec::Vec};
+        use core::error::Error as _;
+
+        use crate::prelude::*;
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("First error");
+        errs.push("Second error");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "First error");
+
+        let sources: Vec<_> = err
+            .sources()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(sources, ["First error", "Second error"]);
+    }
+
+    #[test]
+    #[cfg(feature = "rust-v1.81")]
+    fn source_of_ad_hoc_error_is_none() {
+        use core::error::Error as _;
+
+        use crate::prelude::*;
+
+        let err: Error = Error::from_message("Ad-hoc error");
+        assert!(err.source().is_none());
+        assert_eq!(err.sources().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "rust-v1.81")]
+    fn source_chain_contains_all_levels() {
+        use alloc::string::ToString;
+        use core::error::Error as _;
+
+        use crate::prelude::*;
+
+        let inner: Error = Error::from_message("Root cause");
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push(inner);
+        let stashed: Error = errs.into_result().unwrap_err();
+        let err: Error = Error::wrap_with(stashed, "Context");
+
+        let mut chain = vec![];
+        let mut source = err.source();
+        while let Some(e) = source {
+            chain.push(e.to_string());
+            source = e.source();
+        }
+
+        assert_eq!(chain, ["Summary: Root cause", "Root cause"]);
+    }
+
+    #[test]
+    #[cfg(feature = "rust-v1.81")]
+    fn surrogate_errors_are_not_exposed_as_source() {
+        use core::error::Error as _;
+
+        use crate::surrogate_error_trait::prelude::*;
+
+        let err: Error = Error::wrap("Inner error");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn find_searches_all_levels_with_stashable_any() {
+        use crate::surrogate_error_trait::StashableAny;
+
+        type Error = crate::Error<StashableAny>;
+        type ErrorStash<F, M> = crate::ErrorStash<F, M, StashableAny>;
+
+        let mut inner = ErrorStash::new(|| "Inner errors");
+        inner.push(Error::from_message("Ad-hoc error"));
+        inner.push(Error::wrap(core::fmt::Error));
+        let inner: Error = inner.into_result().unwrap_err();
+
+        let mut errs = ErrorStash::new(|| "Outer errors");
+        errs.push(Error::wrap_with(inner, "Context"));
+        let err: Error = errs.into_result().unwrap_err();
+
+        assert!(err.find::<core::fmt::Error>().is_some());
+        assert!(err
+            .find::<core::num::ParseIntError>()
+            .is_none());
+
+        let ad_hoc = err.find::<super::AdHocError>().unwrap();
+        assert_eq!(ad_hoc.message(), "Ad-hoc error");
+
+        assert_eq!(
+            err.find_all::<super::WrappedError<StashableAny>>()
+                .count(),
+            2
+        );
+        assert!(err.any(|e: &super::StashedErrors<StashableAny>| {
+            e.summary() == "Inner errors"
+        }));
+    }
+
+    #[test]
+    #[cfg(feature = "backtrace")]
+    fn backtrace_is_printed_only_if_captured() {
+        use std::backtrace::BacktraceStatus;
+
+        use crate::prelude::*;
+
+        let err: Error = Error::from_message("Ad-hoc error");
+        let backtrace = match err.as_ref() {
+            ErrorData::AdHoc(err) => err.backtrace(),
+            _ => unreachable!(),
+        };
+
+        let is_captured = backtrace.status() == BacktraceStatus::Captured;
+        let printed = format!("{err:#}");
+        assert_eq!(printed.contains("stack backtrace:"), is_captured);
+        assert_eq!(format!("{err}"), "Ad-hoc error");
+    }
+
+    #[test]
+    #[cfg(feature = "backtrace")]
+    fn captured_backtraces_are_printed_in_pretty_format() {
+        use std::backtrace::Backtrace;
+
+        use super::{location, Extras};
+        use crate::{
+            doctest_line_num_helper as replace_line_numbers, prelude::*,
+        };
+
+        let mut err = AdHocError::from_message("Ad-hoc error");
+        err.backtrace = Backtrace::force_capture();
+        let err: Error = ErrorData::AdHoc(err).into();
+
+        let printed = format!("{err:#}");
+        assert!(printed.contains("\nstack backtrace:\n"));
+        assert_eq!(format!("{err}"), "Ad-hoc error");
+        assert_eq!(replace_line_numbers(&printed), indoc::indoc! {"
+            Ad-hoc error
+            at src/error.rs:1234:56"});
+
+        let errors: Vec<Stashable> = vec!["First".into(), "Second".into()];
+        let extras = Extras {
+            backtrace: Some(Backtrace::force_capture()),
+            ..Extras::default()
+        };
+        let errs = StashedErrors::from("Summary", errors, [location(); 2])
+            .with_extras(vec![extras]);
+        let err: Error = ErrorData::Stashed(errs).into();
+
+        let printed = format!("{err:#}");
+        assert!(printed.contains("\n  stack backtrace:\n"));
+        assert_eq!(replace_line_numbers(&printed), indoc::indoc! {"
+            Summary
+            - First
+              at src/error.rs:1234:56
+            - Second
+              at src/error.rs:1234:56"});
+    }
+
+    #[test]
+    #[cfg(feature = "backtrace")]
+    fn backtraces_are_stored_for_each_stashed_error_if_captured() {
+        use std::backtrace::{Backtrace, BacktraceStatus};
+
+        use crate::prelude::*;
+
+        let is_enabled =
+            Backtrace::capture().status() == BacktraceStatus::Captured;
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("First error");
+        errs.push("Second error");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let errs = match err.as_ref() {
+            ErrorData::Stashed(errs) => errs,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(errs.entry_backtrace(0).is_some(), is_enabled);
+        assert_eq!(errs.entry_backtrace(1).is_some(), is_enabled);
+    }
+
+    #[test]
+    fn into_parts_keeps_data_of_stashed_errors() {
+        use crate::{
+            surrogate_error_trait::prelude::*, Attachments, Retention, Severity,
+        };
+
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::First(2));
+        errs.push_with_kind("First error", 42_u8);
+        errs.push_with_attachments(
+            "Second error",
+            Attachments::new().with_field("key", "value"),
+        );
+        errs.push_warning("Third error");
+
+        let err: Error = errs.into_result().unwrap_err();
+        let parts = match ErrorData::from(err) {
+            ErrorData::Stashed(errs) => errs.into_parts(),
+            _ => unreachable!(),
+        };
+
+        let kind = parts.entries[0]
+            .kind
+            .as_deref()
+            .unwrap();
+        assert_eq!(kind.as_any().downcast_ref::<u8>(), Some(&42));
+        assert_eq!(
+            parts.entries[1]
+                .attachments
+                .field("key"),
+            Some("value")
+        );
+        assert_eq!(parts.entries[1].severity, Severity::Error);
+        assert_eq!(parts.omitted_errors, 0);
+        assert_eq!(parts.omitted_warnings, 1);
+        assert!(!parts.aborted);
+    }
+
     fn assert_small<T>() {
         use core::mem::size_of;
         assert_eq!(size_of::<T>(), size_of::<usize>());
@@ -0,0 +1,291 @@
+use core::fmt::{self, Write};
+
+use std::error::Error as StdError;
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{render::Indented, report::default_renderer, Renderer};
+
+/// An [`eyre::EyreHandler`] that prints errors from this crate
+/// in the same way as [`Renderer`] does.
+///
+/// By default, [`eyre::Report`] prints the chain of
+/// [`source`](core::error::Error::source)s of an error.
+/// Since an [`Error`] can contain any number of errors,
+/// but exposes only one of them as `source`, most of the errors
+/// would be missing from that chain.
+/// When installed via [`eyre::set_hook`], this handler
+/// prints the entire tree of errors instead, including
+/// the summaries, bullets, and source locations, as soon as
+/// it encounters an [`Error`] in the chain of errors of the report.
+/// Errors preceding that [`Error`] in the chain
+/// are printed in the same way as eyre's default handler
+/// prints causes of an error.
+/// If there is no [`Error`] in the chain at all,
+/// or when the report is printed via `{:#?}`,
+/// this handler falls back to eyre's default handler.
+///
+/// Since [`eyre::Report`] is not generic, this handler only recognizes
+/// the [`Error`] types from [`prelude`](crate::prelude) and
+/// [`surrogate_error_trait::prelude`](crate::surrogate_error_trait::prelude).
+/// Please note that [`into_eyre_report`] puts the [`Error`]
+/// into the [`eyre::Report`] as-is,
+/// while the lossy variants of that method do not.
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// use eyre::WrapErr;
+/// use lazy_errors::{prelude::*, EyreHandler};
+///
+/// fn run() -> eyre::Result<()> {
+///     let mut stash = ErrorStash::new(|| "Failed to run");
+///     stash.push("First error");
+///     stash.push("Second error");
+///     stash
+///         .into_eyre_result()
+///         .wrap_err("Failed to start")
+/// }
+///
+/// eyre::set_hook(Box::new(EyreHandler::default_with)).unwrap();
+///
+/// let report = run().unwrap_err();
+/// let printed = format!("{report:?}");
+/// let printed = replace_line_numbers(&printed);
+/// assert_eq!(printed, indoc::indoc! {"
+///     Failed to start
+///
+///     Caused by:
+///         Failed to run
+///         - First error
+///           at src/eyre_handler.rs:1234:56
+///         - Second error
+///           at src/eyre_handler.rs:1234:56"});
+/// ```
+///
+/// If you want to print errors differently, use [`EyreHandler::hook`]
+/// to install a handler that uses a custom [`Renderer`].
+///
+/// [`Error`]: crate::Error
+/// [`into_eyre_report`]: crate::IntoEyreReport::into_eyre_report
+pub struct EyreHandler {
+    renderer: Renderer,
+    fallback: Box<dyn eyre::EyreHandler>,
+}
+
+impl EyreHandler {
+    /// Creates an [`EyreHandler`] that prints errors
+    /// by the default [`Renderer`].
+    /// If the `color` feature is enabled, the default [`Renderer`]
+    /// highlights the output as described in `Renderer::with_auto_color`
+    /// if `stderr` is a terminal.
+    ///
+    /// Pass this function to [`eyre::set_hook`] to install the handler:
+    ///
+    /// ```
+    /// use lazy_errors::EyreHandler;
+    ///
+    /// eyre::set_hook(Box::new(EyreHandler::default_with)).unwrap();
+    /// ```
+    pub fn default_with(
+        error: &(dyn StdError + 'static),
+    ) -> Box<dyn eyre::EyreHandler> {
+        Box::new(Self::new(error, default_renderer()))
+    }
+
+    /// Returns a hook that creates an [`EyreHandler`]
+    /// which prints errors by the given [`Renderer`].
+    ///
+    /// Pass the hook to [`eyre::set_hook`] to install the handler:
+    ///
+    /// ```
+    /// use lazy_errors::{EyreHandler, Layout, Renderer};
+    ///
+    /// let renderer = Renderer::new().with_layout(Layout::Tree);
+    /// eyre::set_hook(Box::new(EyreHandler::hook(renderer))).unwrap();
+    /// ```
+    pub fn hook(
+        renderer: Renderer,
+    ) -> impl Fn(&(dyn StdError + 'static)) -> Box<dyn eyre::EyreHandler>
+           + Send
+           + Sync
+           + 'static {
+        move |error| Box::new(Self::new(error, renderer.clone()))
+    }
+
+    fn new(error: &(dyn StdError + 'static), renderer: Renderer) -> Self {
+        Self {
+            renderer,
+            fallback: eyre::DefaultHandler::default_with(error),
+        }
+    }
+
+    /// Prints `error` by the [`Renderer`] if it is an [`Error`]
+    /// from this crate. Returns `None` otherwise.
+    ///
+    /// [`Error`]: crate::Error
+    fn render(
+        &self,
+        f: &mut dyn Write,
+        error: &(dyn StdError + 'static),
+    ) -> Option<fmt::Result> {
+        if let Some(err) = error.downcast_ref::<crate::prelude::Error>() {
+            return Some(write!(f, "{}", self.renderer.render(err)));
+        }
+
+        use crate::surrogate_error_trait::prelude::Error;
+        if let Some(err) = error.downcast_ref::<Error>() {
+            return Some(write!(f, "{}", self.renderer.render(err)));
+        }
+
+        None
+    }
+}
+
+impl eyre::EyreHandler for EyreHandler {
+    fn debug(
+        &self,
+        error: &(dyn StdError + 'static),
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if f.alternate() {
+            return self.fallback.debug(error, f);
+        }
+
+        let chain: Vec<&(dyn StdError + 'static)> =
+            core::iter::successors(Some(error), |err| (*err).source())
+                .collect();
+
+        let index = chain
+            .iter()
+            .position(|err| is_error_tree(*err));
+
+        let causes = match index {
+            None => return self.fallback.debug(error, f),
+            Some(0) => {
+                return self
+                    .render(f, error)
+                    .unwrap_or_else(|| self.fallback.debug(error, f))
+            }
+            Some(i) => &chain[1..=i],
+        };
+
+        write!(f, "{error}\n\nCaused by:")?;
+
+        let is_multiple = causes.len() > 1;
+        for (n, cause) in causes.iter().enumerate() {
+            writeln!(f)?;
+            let indent = if is_multiple {
+                write!(f, "{n:>4}: ")?;
+                "      "
+            } else {
+                write!(f, "    ")?;
+                "    "
+            };
+
+            let mut f = Indented { inner: f, indent };
+            match self.render(&mut f, *cause) {
+                Some(result) => result?,
+                None => write!(f, "{cause}")?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn display(
+        &self,
+        error: &(dyn StdError + 'static),
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        self.fallback.display(error, f)
+    }
+
+    fn track_caller(
+        &mut self,
+        location: &'static std::panic::Location<'static>,
+    ) {
+        self.fallback.track_caller(location);
+    }
+}
+
+impl fmt::Debug for EyreHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EyreHandler")
+            .field("renderer", &self.renderer)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns whether `error` is an [`Error`](crate::Error) from this crate
+/// that [`EyreHandler`] is able to print.
+fn is_error_tree(error: &(dyn StdError + 'static)) -> bool {
+    error.is::<crate::prelude::Error>()
+        || error.is::<crate::surrogate_error_trait::prelude::Error>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        error::Error as StdError,
+        fmt::{self, Display},
+        format,
+    };
+
+    use crate::{prelude::*, strip_backtraces, EyreHandler, Renderer};
+
+    /// Prints `error` via [`eyre::EyreHandler::debug`].
+    struct Debugged<'a>(&'a (dyn StdError + 'static));
+
+    impl Display for Debugged<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let renderer = Renderer::new().with_locations(false);
+            let handler = EyreHandler::hook(renderer)(self.0);
+            handler.debug(self.0, f)
+        }
+    }
+
+    fn error_tree() -> Error {
+        let mut errs = ErrorStash::new(|| "Failed to run");
+        errs.push("First error");
+        errs.push("Second error");
+        errs.into_result().unwrap_err()
+    }
+
+    #[test]
+    fn root_is_rendered() {
+        let err = error_tree();
+        let printed = strip_backtraces(&format!("{}", Debugged(&err)));
+        assert_eq!(printed, "Failed to run\n- First error\n- Second error");
+    }
+
+    #[test]
+    fn causes_are_numbered_if_multiple() {
+        let report = eyre::Report::new(error_tree())
+            .wrap_err("Failed to start")
+            .wrap_err("Failed to deploy");
+
+        let err: &(dyn StdError + 'static) = report.as_ref();
+        let printed = strip_backtraces(&format!("{}", Debugged(err)));
+        assert_eq!(printed, indoc::indoc! {"
+            Failed to deploy
+
+            Caused by:
+               0: Failed to start
+               1: Failed to run
+                  - First error
+                  - Second error"});
+    }
+
+    #[test]
+    fn falls_back_to_default_handler() {
+        let report = eyre::Report::new(fmt::Error).wrap_err("Failed");
+
+        let err: &(dyn StdError + 'static) = report.as_ref();
+        let printed = format!("{}", Debugged(err));
+        assert!(printed.starts_with(indoc::indoc! {"
+            Failed
+
+            Caused by:
+                an error occurred when formatting an argument"}));
+    }
+}
@@ -0,0 +1,307 @@
+/// Makes an [`ErrorStash`] stop processing once too many errors
+/// have been added to it.
+///
+/// By default, stashes collect all errors, no matter how many.
+/// If collecting more errors is pointless after some point,
+/// for example because the input turned out to be garbage,
+/// you can set a `FailFast` policy by calling `with_fail_fast`
+/// on [`ErrorStash`] or [`StashWithErrors`].
+/// Once the limit of the policy has been reached,
+/// [`or_stash`] returns [`StashedResult::LimitReached`] instead of `Err`
+/// (which makes [`try2!`] return immediately),
+/// [`stash_err`] stops iterating,
+/// and [`try_collect_or_stash`] stops collecting.
+/// The error created from the stash will note that processing
+/// has been aborted early:
+///
+/// ```
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// use lazy_errors::prelude::*;
+///
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// use lazy_errors::surrogate_error_trait::prelude::*;
+///
+/// use lazy_errors::FailFast;
+///
+/// let mut errs = ErrorStash::new(|| "Failed to import")
+///     .with_fail_fast(FailFast::MaxErrors(2));
+///
+/// let numbers: Vec<u32> = ["1", "A", "3", "B", "5", "C"]
+///     .iter()
+///     .map(|s| s.parse::<u32>())
+///     .stash_err(&mut errs)
+///     .collect();
+///
+/// assert_eq!(numbers, [1, 3]);
+/// assert!(errs.is_limit_reached());
+///
+/// let err: Error = errs.into_result().unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Failed to import (2 errors, aborted early)"
+/// );
+/// ```
+///
+/// [`FailFast::MaxErrorRatio`] takes the number of successes into account.
+/// Successes are recorded by [`or_stash`] (and thus by [`stash_err`] and
+/// [`try_collect_or_stash`]) for each `Ok` value.
+/// If you're handling errors in a different way, you can record successes
+/// by calling `record_success` on [`ErrorStash`] or [`StashWithErrors`].
+///
+/// The limit is checked whenever an error is added to the stash.
+/// Errors added as [`Severity::Warning`] are not taken into account.
+/// Once the limit has been reached, the stash will not reset,
+/// but you can still add errors to it.
+///
+/// [`ErrorStash`]: crate::ErrorStash
+/// [`StashWithErrors`]: crate::StashWithErrors
+/// [`StashedResult::LimitReached`]: crate::StashedResult::LimitReached
+/// [`Severity::Warning`]: crate::Severity::Warning
+/// [`try2!`]: crate::try2!
+/// [`or_stash`]: crate::OrStash::or_stash
+/// [`stash_err`]: crate::StashErr::stash_err
+/// [`try_collect_or_stash`]: crate::TryCollectOrStash::try_collect_or_stash
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailFast {
+    /// Reaches the limit when `n` errors have been added.
+    ///
+    /// A limit of zero is treated as a limit of one.
+    MaxErrors(usize),
+
+    /// Reaches the limit when errors make up at least `ratio`
+    /// of all outcomes (errors and recorded successes),
+    /// but not before `min_outcomes` outcomes have been seen.
+    ///
+    /// For example, `MaxErrorRatio { ratio: 0.5, min_outcomes: 10 }`
+    /// reaches the limit if at least half of the first ten
+    /// (or more) outcomes were errors.
+    MaxErrorRatio { ratio: f64, min_outcomes: usize },
+}
+
+impl FailFast {
+    /// Returns `true` if the limit of this policy has been reached
+    /// after `failures` errors and `successes` successes.
+    pub(crate) fn is_reached(self, failures: usize, successes: usize) -> bool {
+        match self {
+            Self::MaxErrors(n) => failures >= Ord::max(n, 1),
+            Self::MaxErrorRatio {
+                ratio,
+                min_outcomes,
+            } => {
+                let outcomes = failures + successes;
+                failures > 0
+                    && outcomes >= min_outcomes
+                    && failures as f64 >= ratio * outcomes as f64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Display;
+
+    use alloc::{string::ToString, vec::Vec};
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::{prelude::*, Result};
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::{prelude::*, Result};
+
+    use crate::FailFast;
+
+    fn parse_all<F, M>(tokens: &[&str], errs: &mut ErrorStash<F, M>) -> Vec<u32>
+    where
+        F: FnOnce() -> M,
+        M: Display,
+    {
+        let mut numbers = Vec::new();
+        for token in tokens {
+            match token.parse::<u32>().or_stash(errs) {
+                StashedResult::Ok(n) => numbers.push(n),
+                StashedResult::Err(_) => continue,
+                StashedResult::LimitReached(_) => break,
+            }
+        }
+        numbers
+    }
+
+    #[test]
+    fn max_errors() {
+        assert!(!FailFast::MaxErrors(3).is_reached(2, 100));
+        assert!(FailFast::MaxErrors(3).is_reached(3, 0));
+        assert!(!FailFast::MaxErrors(0).is_reached(0, 0));
+        assert!(FailFast::MaxErrors(0).is_reached(1, 0));
+    }
+
+    #[test]
+    fn max_error_ratio() {
+        let policy = FailFast::MaxErrorRatio {
+            ratio: 0.5,
+            min_outcomes: 4,
+        };
+
+        assert!(!policy.is_reached(2, 1));
+        assert!(policy.is_reached(2, 2));
+        assert!(!policy.is_reached(2, 3));
+        assert!(!policy.is_reached(0, 4));
+
+        let policy = FailFast::MaxErrorRatio {
+            ratio: 0.0,
+            min_outcomes: 0,
+        };
+
+        assert!(!policy.is_reached(0, 4));
+        assert!(policy.is_reached(1, 4));
+    }
+
+    #[test]
+    fn or_stash_signals_limit_reached() {
+        let tokens = ["1", "A", "3", "B", "5", "C"];
+
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_fail_fast(FailFast::MaxErrors(3));
+        assert_eq!(parse_all(&tokens, &mut errs), [1, 3, 5]);
+        assert!(errs.is_limit_reached());
+        assert!(matches!(errs.ok(), StashedResult::LimitReached(_)));
+
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_fail_fast(FailFast::MaxErrors(2));
+        assert_eq!(parse_all(&tokens, &mut errs), [1, 3]);
+
+        let err: Error = errs.into_result().unwrap_err();
+        assert_eq!(err.children().len(), 2);
+        assert_eq!(err.to_string(), "Summary (2 errors, aborted early)");
+    }
+
+    #[test]
+    fn or_stash_keeps_ok_values_once_limit_reached() {
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_fail_fast(FailFast::MaxErrors(1));
+
+        let result = "A".parse::<u32>().or_stash(&mut errs);
+        assert!(matches!(result, StashedResult::LimitReached(_)));
+
+        let result = "2".parse::<u32>().or_stash(&mut errs);
+        assert!(matches!(result, StashedResult::Ok(2)));
+
+        let result = "B".parse::<u32>().or_stash(&mut errs);
+        assert!(matches!(result, StashedResult::LimitReached(_)));
+
+        let numbers: Vec<u32> = ["3", "4"]
+            .iter()
+            .map(|s| s.parse::<u32>())
+            .stash_err(&mut errs)
+            .collect();
+        assert!(numbers.is_empty());
+    }
+
+    #[test]
+    fn try2_returns_once_limit_reached() {
+        fn parse_all(tokens: &[&str]) -> Result<Vec<u32>> {
+            let mut errs = ErrorStash::new(|| "Summary")
+                .with_fail_fast(FailFast::MaxErrors(1));
+
+            let numbers = try2!(tokens
+                .iter()
+                .map(|s| s.parse::<u32>())
+                .try_collect_or_stash::<Vec<u32>>(&mut errs));
+
+            Ok(numbers)
+        }
+
+        assert_eq!(parse_all(&["1", "2"]).unwrap(), [1, 2]);
+
+        let err = parse_all(&["1", "A", "B"]).unwrap_err();
+        assert_eq!(err.children().len(), 1);
+        assert!(err
+            .to_string()
+            .ends_with("(aborted early)"));
+    }
+
+    #[test]
+    fn try_map_or_stash_stops_once_limit_reached() {
+        let mut calls = 0;
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_fail_fast(FailFast::MaxErrors(1));
+
+        let result = ["1", "A", "B", "4"].try_map_or_stash(
+            |s| {
+                calls += 1;
+                s.parse::<u32>()
+            },
+            &mut errs,
+        );
+
+        assert!(matches!(result, StashedResult::LimitReached(_)));
+        assert_eq!(calls, 2);
+        assert_eq!(errs.errors().len(), 1);
+    }
+
+    #[test]
+    fn successes_are_recorded() {
+        let policy = FailFast::MaxErrorRatio {
+            ratio: 0.5,
+            min_outcomes: 4,
+        };
+
+        let mut errs = ErrorStash::new(|| "Summary").with_fail_fast(policy);
+        let numbers: Vec<u32> = ["1", "2", "A", "4", "B"]
+            .iter()
+            .map(|s| s.parse::<u32>())
+            .stash_err(&mut errs)
+            .collect();
+
+        assert_eq!(numbers, [1, 2, 4]);
+        assert!(!errs.is_limit_reached());
+
+        errs.push("Error");
+        assert!(errs.is_limit_reached());
+
+        let mut errs = ErrorStash::new(|| "Summary").with_fail_fast(policy);
+        errs.record_success();
+        errs.record_success();
+        errs.push("Error");
+        errs.push("Error");
+        assert!(errs.is_limit_reached());
+
+        let mut errs = ErrorStash::new(|| "Summary").with_fail_fast(policy);
+        assert_eq!(
+            "1".parse::<u32>()
+                .or_stash_warning(&mut errs),
+            Some(1)
+        );
+        assert_eq!(
+            "2".parse::<u32>()
+                .or_stash_warning(&mut errs),
+            Some(2)
+        );
+        errs.push("Error");
+        errs.push("Error");
+        assert!(errs.is_limit_reached());
+    }
+
+    #[test]
+    fn warnings_are_ignored() {
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_fail_fast(FailFast::MaxErrors(1));
+
+        errs.push_warning("Warning");
+        assert!(!errs.is_limit_reached());
+        assert!(matches!(errs.ok(), StashedResult::Ok(())));
+
+        errs.push("Error");
+        assert!(errs.is_limit_reached());
+    }
+
+    #[test]
+    fn existing_errors_are_counted() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("Error");
+        errs.push("Error");
+
+        let errs = errs.with_fail_fast(FailFast::MaxErrors(2));
+        assert!(errs.is_limit_reached());
+    }
+}
@@ -0,0 +1,222 @@
+use core::fmt::{self, Display, Write};
+
+use crate::{Diagnostic, ErrorData, InnerError, Severity};
+
+/// Prints the leaves of the entire tree of an [`Error`]
+/// as GitHub Actions workflow commands,
+/// so that they are shown as annotations on pull requests.
+///
+/// Values of this type are created by [`ErrorData::github_annotations`],
+/// which you can call on [`Error`] as well.
+/// Each [`Diagnostic`] is printed on a separate line,
+/// either as `::error` or as `::warning` command, depending on its
+/// [`Severity`]. The source location of the leaf is printed as
+/// `file`, `line`, and `col` parameters, using `/` as path separator
+/// on all platforms. The summaries and contexts of its ancestors
+/// are printed as `title`.
+/// Newlines, `%`, and (in parameters) `:` and `,` are escaped
+/// as required by GitHub.
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// use lazy_errors::prelude::*;
+///
+/// let mut errs = ErrorStash::new(|| "Lint: 2 issues");
+/// errs.push("Line is too long\n(100% > 80)");
+/// errs.push_warning(Error::from_message("Trailing whitespace"));
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let locations: Vec<_> = err
+///     .diagnostics()
+///     .filter_map(|diagnostic| diagnostic.location())
+///     .collect();
+///
+/// let file = file!().replace('\\', "/");
+/// let (first, second) = (locations[0], locations[1]);
+/// let expected = format!(
+///     "::error file={file},line={},col={},title=Lint%3A 2 issues\
+///      ::Line is too long%0A(100%25 > 80)\n\
+///      ::warning file={file},line={},col={},title=Lint%3A 2 issues\
+///      ::Trailing whitespace",
+///     first.line(),
+///     first.column(),
+///     second.line(),
+///     second.column(),
+/// );
+///
+/// let printed = err.github_annotations().to_string();
+/// assert_eq!(printed, expected);
+/// # }
+/// ```
+///
+/// [`Error`]: crate::Error
+#[derive(Debug)]
+pub struct GithubAnnotations<'a, I> {
+    error: &'a ErrorData<I>,
+}
+
+/// Writes to `inner`, escaping characters
+/// that must not appear verbatim in workflow commands.
+struct Escaped<'a, W: ?Sized> {
+    inner: &'a mut W,
+    is_property: bool,
+}
+
+impl<'a, I> GithubAnnotations<'a, I> {
+    pub(crate) fn new(error: &'a ErrorData<I>) -> Self {
+        Self { error }
+    }
+}
+
+impl<I> Display for GithubAnnotations<'_, I>
+where
+    I: Display + InnerError,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, diagnostic) in self.error.diagnostics().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            display_annotation(f, &diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+fn display_annotation<I: Display>(
+    f: &mut fmt::Formatter<'_>,
+    diagnostic: &Diagnostic<'_, I>,
+) -> fmt::Result {
+    let command = match diagnostic.severity() {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    };
+
+    write!(f, "::{command}")?;
+
+    let mut separator = " ";
+    if let Some(location) = diagnostic.location() {
+        write!(f, " file=")?;
+        let file = location.file().replace('\\', "/");
+        Escaped::property(f).write_str(&file)?;
+        write!(f, ",line={},col={}", location.line(), location.column())?;
+        separator = ",";
+    }
+
+    if let Some((first, rest)) = diagnostic.context().split_first() {
+        write!(f, "{separator}title=")?;
+        let mut title = Escaped::property(f);
+        title.write_str(first)?;
+        for context in rest {
+            write!(title, ": {context}")?;
+        }
+    }
+
+    write!(f, "::")?;
+    diagnostic.display_message(&mut Escaped::message(f))
+}
+
+impl<'a, W: Write + ?Sized> Escaped<'a, W> {
+    fn message(inner: &'a mut W) -> Self {
+        Self {
+            inner,
+            is_property: false,
+        }
+    }
+
+    fn property(inner: &'a mut W) -> Self {
+        Self {
+            inner,
+            is_property: true,
+        }
+    }
+}
+
+impl<W: Write + ?Sized> Write for Escaped<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '%' => self.inner.write_str("%25")?,
+                '\r' => self.inner.write_str("%0D")?,
+                '\n' => self.inner.write_str("%0A")?,
+                ':' if self.is_property => self.inner.write_str("%3A")?,
+                ',' if self.is_property => self.inner.write_str("%2C")?,
+                c => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+    };
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use super::Escaped;
+    use core::fmt::Write;
+
+    #[test]
+    fn escape_message() {
+        let mut s = String::new();
+        write!(Escaped::message(&mut s), "50%\r\na: b, c").unwrap();
+        assert_eq!(s, "50%25%0D%0Aa: b, c");
+    }
+
+    #[test]
+    fn escape_property() {
+        let mut s = String::new();
+        write!(Escaped::property(&mut s), "50%\r\na: b, c").unwrap();
+        assert_eq!(s, "50%25%0D%0Aa%3A b%2C c");
+    }
+
+    #[test]
+    fn ad_hoc_error_has_no_title() {
+        let err: Error = Error::from_message("Oops");
+        let location = err
+            .walk()
+            .next()
+            .and_then(|node| node.location())
+            .unwrap();
+
+        let printed = err.github_annotations().to_string();
+        let expected = format!(
+            "::error file={},line={},col={}::Oops",
+            location.file().replace('\\', "/"),
+            location.line(),
+            location.column()
+        );
+        assert_eq!(printed, expected);
+    }
+
+    #[test]
+    fn path_separators_are_normalized() {
+        use crate::{OwnedError, OwnedLocation, Severity};
+
+        let err: Error = Error::from(OwnedError::AdHoc {
+            message:  "Remote error".into(),
+            fields:   vec![],
+            location: OwnedLocation {
+                file:   "src\\remote.rs".into(),
+                line:   42,
+                column: 7,
+            },
+            severity: Severity::Error,
+        });
+
+        let printed = err.github_annotations().to_string();
+        assert_eq!(
+            printed,
+            "::error file=src/remote.rs,line=42,col=7::Remote error"
+        );
+    }
+}
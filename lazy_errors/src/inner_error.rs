@@ -0,0 +1,287 @@
+#[cfg(feature = "rust-v1.81")]
+use core::error::Error as StdError;
+
+#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
+use std::error::Error as StdError;
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+use core::fmt::{Debug, Display};
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+use alloc::boxed::Box;
+
+use core::any::Any;
+
+use crate::NodeKind;
+
+use crate::{AdHocError, Error, ErrorData, StashedErrors, WrappedError};
+
+/// Allows `lazy_errors` to look into values of the
+/// [_inner error type_ `I`](crate::Error#inner-error-type-i).
+///
+/// The error types of this crate, such as [`Error`] or [`WrappedError`],
+/// implement `core::error::Error` only if `I` implements this trait.
+/// This trait enables them to return their inner error(s) from
+/// `core::error::Error::source`. Thus, other crates that walk the
+/// chain of error sources (e.g. error reporters) will be able to see
+/// the errors that have been wrapped or stashed.
+///
+/// `lazy_errors` implements this trait for the boxed
+/// `dyn core::error::Error` types used as `I` by the [`prelude`].
+/// When you're using the [`surrogate_error_trait::prelude`] instead,
+/// the trait is implemented as well, but errors cannot be exposed
+/// as sources because they don't implement `core::error::Error`.
+///
+/// If you're using a custom inner error type, implementing this trait is
+/// usually a one-liner. In that case, the inner errors won't be exposed
+/// via `core::error::Error::source`:
+///
+/// ```
+/// use lazy_errors::{Error, InnerError};
+///
+/// #[derive(thiserror::Error, Debug)]
+/// #[error("Input '{0}' is invalid")]
+/// struct InvalidInput<'a>(&'a str);
+///
+/// impl InnerError for InvalidInput<'_> {}
+///
+/// let err: Error<InvalidInput> = Error::wrap(InvalidInput("❌"));
+/// # #[cfg(feature = "rust-v1.81")]
+/// let err: &dyn core::error::Error = &err;
+/// ```
+///
+/// If your custom error type implements `core::error::Error` and
+/// is `'static`, you can expose it as source as well:
+///
+/// ```
+/// # #[cfg(feature = "rust-v1.81")]
+/// # {
+/// use core::error::Error as _;
+/// use lazy_errors::{Error, InnerError};
+///
+/// #[derive(thiserror::Error, Debug)]
+/// #[error("Input is empty")]
+/// struct EmptyInput;
+///
+/// impl InnerError for EmptyInput {
+///     fn as_error_source(
+///         &self,
+///     ) -> Option<&(dyn core::error::Error + 'static)> {
+///         Some(self)
+///     }
+/// }
+///
+/// let err: Error<EmptyInput> = Error::wrap(EmptyInput);
+/// let source = err.source().unwrap();
+/// assert!(source.is::<EmptyInput>());
+/// # }
+/// ```
+///
+/// [`Error`]: crate::Error
+/// [`WrappedError`]: crate::WrappedError
+/// [`surrogate_error_trait::prelude`]: crate::surrogate_error_trait::prelude
+#[cfg_attr(
+    any(feature = "rust-v1.81", feature = "std"),
+    doc = r##"
+[`prelude`]: crate::prelude
+"##
+)]
+#[cfg_attr(
+    not(any(feature = "rust-v1.81", feature = "std")),
+    doc = r##"
+[`prelude`]: crate::surrogate_error_trait::prelude
+"##
+)]
+pub trait InnerError {
+    /// Returns `self` (or the error boxed by `self`) as trait object,
+    /// so it can be returned from `core::error::Error::source`.
+    ///
+    /// Returns `None` by default.
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn as_error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        None
+    }
+
+    /// Returns `self` (or the error boxed by `self`) as [`NodeKind`]
+    /// if `self` is one of the error types of this crate,
+    /// i.e. an [`Error<Self>`](crate::Error) or the like.
+    ///
+    /// This method allows [`walk`](crate::ErrorData::walk)
+    /// to descend into nested errors.
+    ///
+    /// Returns `None` by default.
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// Allows `lazy_errors` to downcast values of the
+/// [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// to the concrete type `T`.
+///
+/// This trait is required by [`find`], [`find_all`], and [`any`],
+/// which search for errors of type `T` in the entire tree of an error.
+///
+/// `lazy_errors` implements this trait for the boxed `dyn core::error::Error`
+/// types used as `I` by the regular `prelude`,
+/// in which case `T` needs to implement `core::error::Error`.
+/// The [`surrogate_error_trait::Stashable`] type does not support
+/// downcasting. If you need to downcast errors in that case,
+/// please use [`surrogate_error_trait::StashableAny`] instead.
+///
+/// [`find`]: crate::ErrorData::find
+/// [`find_all`]: crate::ErrorData::find_all
+/// [`any`]: crate::ErrorData::any
+/// [`surrogate_error_trait::Stashable`]:
+/// crate::surrogate_error_trait::Stashable
+/// [`surrogate_error_trait::StashableAny`]:
+/// crate::surrogate_error_trait::StashableAny
+pub trait DowncastInner<T>: InnerError {
+    /// Returns `self` (or the error boxed by `self`) as `T`
+    /// if it is a value of type `T`.
+    fn downcast_inner(&self) -> Option<&T>;
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl InnerError for Box<dyn StdError + 'static> {
+    fn as_error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.as_ref())
+    }
+
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree(self.as_ref())
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl InnerError for Box<dyn StdError + Send + 'static> {
+    fn as_error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.as_ref())
+    }
+
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree(self.as_ref())
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl InnerError for Box<dyn StdError + Sync + 'static> {
+    fn as_error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.as_ref())
+    }
+
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree(self.as_ref())
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl InnerError for Box<dyn StdError + Send + Sync + 'static> {
+    fn as_error_source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.as_ref())
+    }
+
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree(self.as_ref())
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<T: StdError + 'static> DowncastInner<T> for Box<dyn StdError + 'static> {
+    fn downcast_inner(&self) -> Option<&T> {
+        let err: &(dyn StdError + 'static) = self.as_ref();
+        err.downcast_ref::<T>()
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<T: StdError + 'static> DowncastInner<T>
+    for Box<dyn StdError + Send + 'static>
+{
+    fn downcast_inner(&self) -> Option<&T> {
+        let err: &(dyn StdError + 'static) = self.as_ref();
+        err.downcast_ref::<T>()
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<T: StdError + 'static> DowncastInner<T>
+    for Box<dyn StdError + Sync + 'static>
+{
+    fn downcast_inner(&self) -> Option<&T> {
+        let err: &(dyn StdError + 'static) = self.as_ref();
+        err.downcast_ref::<T>()
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl<T: StdError + 'static> DowncastInner<T>
+    for Box<dyn StdError + Send + Sync + 'static>
+{
+    fn downcast_inner(&self) -> Option<&T> {
+        let err: &(dyn StdError + 'static) = self.as_ref();
+        err.downcast_ref::<T>()
+    }
+}
+
+/// Tries to downcast `err` to any of the error types of this crate
+/// that have `I` as inner error type.
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+fn downcast_error_tree<'a, I>(
+    err: &'a (dyn StdError + 'static),
+) -> Option<NodeKind<'a, I>>
+where
+    I: InnerError + Display + Debug + 'static,
+{
+    if let Some(err) = err.downcast_ref::<Error<I>>() {
+        let data: &ErrorData<I> = err;
+        return Some(NodeKind::from(data));
+    }
+
+    if let Some(err) = err.downcast_ref::<ErrorData<I>>() {
+        return Some(NodeKind::from(err));
+    }
+
+    if let Some(err) = err.downcast_ref::<StashedErrors<I>>() {
+        return Some(NodeKind::Stashed(err));
+    }
+
+    if let Some(err) = err.downcast_ref::<WrappedError<I>>() {
+        return Some(NodeKind::Wrapped(err));
+    }
+
+    err.downcast_ref::<AdHocError>()
+        .map(NodeKind::AdHoc)
+}
+
+/// Tries to downcast `err` to any of the error types of this crate
+/// that have `I` as inner error type.
+pub(crate) fn downcast_error_tree_any<I>(
+    err: &dyn Any,
+) -> Option<NodeKind<'_, I>>
+where
+    I: 'static,
+{
+    if let Some(err) = err.downcast_ref::<Error<I>>() {
+        let data: &ErrorData<I> = err;
+        return Some(NodeKind::from(data));
+    }
+
+    if let Some(err) = err.downcast_ref::<ErrorData<I>>() {
+        return Some(NodeKind::from(err));
+    }
+
+    if let Some(err) = err.downcast_ref::<StashedErrors<I>>() {
+        return Some(NodeKind::Stashed(err));
+    }
+
+    if let Some(err) = err.downcast_ref::<WrappedError<I>>() {
+        return Some(NodeKind::Wrapped(err));
+    }
+
+    err.downcast_ref::<AdHocError>()
+        .map(NodeKind::AdHoc)
+}
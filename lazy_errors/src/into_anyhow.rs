@@ -0,0 +1,460 @@
+use core::fmt::{self, Debug, Display};
+
+use crate::{
+    error::{AdHocError, Error, ErrorData, StashedErrors, WrappedError},
+    stash::{ErrorStash, StashWithErrors},
+    InnerError,
+};
+
+/// Adds the [`into_anyhow_result`](Self::into_anyhow_result) method
+/// on types that can be converted into `Result<T, E>`,
+/// converting them into `Result<T, anyhow::Error>` instead.
+///
+/// Do not implement this trait. Importing the trait is sufficient
+/// due to blanket implementations. The trait is implemented on `R`
+/// if `R` can be converted into `Result<_, E>` and
+/// if `E` implements [`IntoAnyhowError`].
+///
+/// This trait is the `anyhow` counterpart of
+/// [`IntoEyreResult`](crate::IntoEyreResult).
+pub trait IntoAnyhowResult<T, I>
+where
+    I: IntoAnyhowError,
+{
+    /// Lossless conversion to return some type,
+    /// for example a list of errors that may be empty,
+    /// from functions returning [`anyhow::Result`],
+    /// i.e. `Result<_, E>` where `E` is [`anyhow::Error`]:
+    ///
+    /// ```
+    /// use anyhow::Context;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn parse(s: &str) -> anyhow::Result<i32> {
+    ///     use core::str::FromStr;
+    ///     i32::from_str(s).with_context(|| format!("Not an i32: '{s}'"))
+    /// }
+    ///
+    /// fn parse_all() -> anyhow::Result<()> {
+    ///     let mut stash = ErrorStash::new(|| "Failed to parse");
+    ///
+    ///     parse("🙈").or_stash(&mut stash);
+    ///     parse("🙉").or_stash(&mut stash);
+    ///     parse("🙊").or_stash(&mut stash);
+    ///
+    ///     stash.into_anyhow_result()
+    /// }
+    ///
+    /// let err: anyhow::Error = parse_all().unwrap_err();
+    /// assert_eq!(format!("{err}"), "Failed to parse (3 errors)");
+    ///
+    /// let err: &Error = err.downcast_ref().unwrap();
+    /// assert_eq!(err.children().len(), 3);
+    /// ```
+    ///
+    /// Please take a look at [`IntoAnyhowError`] for details.
+    /// If you'd rather have the [`anyhow::Error`] print the entire tree
+    /// of errors via [`Display`], use [`IntoAnyhowResultLossy`] instead.
+    fn into_anyhow_result(self) -> Result<T, anyhow::Error>;
+}
+
+/// Adds the [`into_anyhow_error`](Self::into_anyhow_error) method
+/// on various error and error builder types.
+///
+/// Do not implement this trait. Importing the trait is sufficient.
+/// The trait is implemented on [`Error`], [`StashWithErrors`],
+/// [`StashedErrors`], [`WrappedError`], and [`AdHocError`]
+/// if the [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// allows the error to be put into an [`anyhow::Error`]
+/// (i.e. if `I` implements [`InnerError`], [`Display`], [`Debug`],
+/// [`Send`], and [`Sync`], and is `'static`).
+///
+/// This trait is the `anyhow` counterpart of
+/// [`IntoEyreReport`](crate::IntoEyreReport).
+pub trait IntoAnyhowError {
+    /// Lossless conversion to return some type, for example
+    /// a non-empty list of one or more errors,
+    /// from functions returning [`anyhow::Result`],
+    /// i.e. `Result<_, E>` where `E` is [`anyhow::Error`].
+    ///
+    /// The error is converted into [`Error`] and then put into the
+    /// [`anyhow::Error`] as-is (via [`anyhow::Error::new`]).
+    /// Thus, you can downcast the [`anyhow::Error`]
+    /// to get the original error back.
+    /// However, [`anyhow::Error`] prints the error in its short form
+    /// (for example, `Summary (2 errors)`) when printed via [`Display`].
+    /// Please take a look at [`IntoAnyhowErrorLossy`]
+    /// if you'd rather have it print the entire tree of errors.
+    /// [`AdHocError`]s are converted into the [`Error`] type
+    /// of the [`prelude`](crate::prelude).
+    ///
+    /// ```
+    /// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+    /// use anyhow::bail;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn run() -> anyhow::Result<()> {
+    ///     let mut stash = ErrorStash::new(|| "One or more things failed");
+    ///     stash.push("first() failed");
+    ///     stash.push("second() failed");
+    ///
+    ///     if let StashedResult::Err(errs) = stash.ok() {
+    ///         bail!(errs.take().into_anyhow_error());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// let err: anyhow::Error = run().unwrap_err();
+    /// assert_eq!(format!("{err}"), "One or more things failed (2 errors)");
+    ///
+    /// let err: &Error = err.downcast_ref().unwrap();
+    /// let printed = format!("{err:#}");
+    /// let printed = replace_line_numbers(&printed);
+    /// assert_eq!(printed, indoc::indoc! {"
+    ///     One or more things failed
+    ///     - first() failed
+    ///       at src/into_anyhow.rs:1234:56
+    ///     - second() failed
+    ///       at src/into_anyhow.rs:1234:56"});
+    /// ```
+    fn into_anyhow_error(self) -> anyhow::Error;
+}
+
+/// Adds the [`into_anyhow_result_lossy`](Self::into_anyhow_result_lossy)
+/// method on types that can be converted into `Result<T, E>`,
+/// converting them into `Result<T, anyhow::Error>` instead.
+///
+/// Do not implement this trait. Importing the trait is sufficient
+/// due to blanket implementations. The trait is implemented on `R`
+/// if `R` can be converted into `Result<_, E>` and
+/// if `E` implements [`IntoAnyhowErrorLossy`].
+///
+/// In contrast to [`IntoAnyhowResult`], the error is converted into a string.
+/// Thus, printing the [`anyhow::Error`] via [`Display`] prints
+/// the entire tree of errors. However, the original error and its
+/// sources cannot be accessed anymore.
+/// This trait is the `anyhow` counterpart of
+/// [`IntoEyreResultLossy`](crate::IntoEyreResultLossy).
+pub trait IntoAnyhowResultLossy<T, I>
+where
+    I: IntoAnyhowErrorLossy,
+{
+    /// Lossy conversion to return some type,
+    /// for example a list of errors that may be empty,
+    /// from functions returning [`anyhow::Result`],
+    /// i.e. `Result<_, E>` where `E` is [`anyhow::Error`]:
+    ///
+    /// ```
+    /// use anyhow::Context;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn parse(s: &str) -> anyhow::Result<i32> {
+    ///     use core::str::FromStr;
+    ///     i32::from_str(s).with_context(|| format!("Not an i32: '{s}'"))
+    /// }
+    ///
+    /// fn parse_all() -> anyhow::Result<()> {
+    ///     let mut stash = ErrorStash::new(|| "Failed to parse");
+    ///
+    ///     parse("🙈").or_stash(&mut stash);
+    ///     parse("🙉").or_stash(&mut stash);
+    ///     parse("🙊").or_stash(&mut stash);
+    ///
+    ///     stash.into_anyhow_result_lossy()
+    /// }
+    ///
+    /// let err: anyhow::Error = parse_all().unwrap_err();
+    /// let msg = format!("{err}");
+    /// assert!(msg.contains("🙈"));
+    /// assert!(msg.contains("🙉"));
+    /// assert!(msg.contains("🙊"));
+    /// ```
+    ///
+    /// Note: This method discards information because
+    /// [`IntoAnyhowErrorLossy`] flattens the type into a single string
+    /// that is then passed to [`anyhow::anyhow!`].
+    /// If you want to keep the original error,
+    /// use [`IntoAnyhowResult`] instead.
+    fn into_anyhow_result_lossy(self) -> Result<T, anyhow::Error>;
+}
+
+/// Adds the [`into_anyhow_error_lossy`](Self::into_anyhow_error_lossy)
+/// method on various error and error builder types.
+///
+/// Do not implement this trait. Importing the trait is sufficient.
+/// The trait is implemented on [`Error`], [`StashWithErrors`],
+/// [`StashedErrors`], [`WrappedError`], and [`AdHocError`].
+///
+/// In contrast to [`IntoAnyhowError`], the error is converted into a string.
+/// Thus, printing the [`anyhow::Error`] via [`Display`] prints
+/// the entire tree of errors. However, the original error and its
+/// sources cannot be accessed anymore.
+/// This trait is the `anyhow` counterpart of
+/// [`IntoEyreReportLossy`](crate::IntoEyreReportLossy).
+pub trait IntoAnyhowErrorLossy {
+    /// Lossy conversion to return some type, for example
+    /// a non-empty list of one or more errors,
+    /// from functions returning [`anyhow::Result`],
+    /// i.e. `Result<_, E>` where `E` is [`anyhow::Error`].
+    ///
+    /// ```
+    /// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+    /// use anyhow::bail;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn run() -> anyhow::Result<()> {
+    ///     let mut stash = ErrorStash::new(|| "One or more things failed");
+    ///     stash.push("first() failed");
+    ///
+    ///     if let StashedResult::Err(errs) = stash.ok() {
+    ///         bail!(errs.take().into_anyhow_error_lossy());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// let err: anyhow::Error = run().unwrap_err();
+    /// let printed = format!("{err}"); // No pretty-printing required
+    /// let printed = replace_line_numbers(&printed);
+    /// assert_eq!(printed, indoc::indoc! {"
+    ///     One or more things failed
+    ///     - first() failed
+    ///       at src/into_anyhow.rs:1234:56"});
+    /// ```
+    ///
+    /// Note: This method discards information because it
+    /// flattens the type into a single string
+    /// that is then passed to [`anyhow::anyhow!`].
+    /// If you want to keep the original error,
+    /// use [`IntoAnyhowError`] instead.
+    fn into_anyhow_error_lossy(self) -> anyhow::Error;
+}
+
+/// Wraps an [`anyhow::Error`] to put it into the containers of this crate
+/// without losing its chain of errors.
+///
+/// `anyhow::Error` can be converted into
+/// [`Stashable`](crate::Stashable) as-is. However, that conversion
+/// unwraps the `anyhow::Error`, so only the outermost error will be printed
+/// when pretty-printing (`{:#}`) the error tree.
+/// When wrapped in `AnyhowError` instead,
+/// pretty-printing prints the entire chain of the `anyhow::Error`
+/// and [`source`](std::error::Error::source) returns
+/// the next error in that chain:
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// use anyhow::Context;
+/// use lazy_errors::{prelude::*, AnyhowError};
+///
+/// fn read_config() -> anyhow::Result<()> {
+///     Err(anyhow::anyhow!("File not found")).context("Failed to read config")
+/// }
+///
+/// let mut errs = ErrorStash::new(|| "Failed to start");
+/// read_config()
+///     .map_err(AnyhowError::from)
+///     .or_stash(&mut errs);
+///
+/// let err: Error = errs.into_result().unwrap_err();
+/// let printed = format!("{err:#}");
+/// let printed = replace_line_numbers(&printed);
+/// assert_eq!(printed, indoc::indoc! {"
+///     Failed to start
+///     - Failed to read config: File not found
+///       at src/into_anyhow.rs:1234:56"});
+///
+/// let source = std::error::Error::source(&err).unwrap();
+/// assert_eq!(source.to_string(), "Failed to read config");
+/// let source = source.source().unwrap();
+/// assert_eq!(source.to_string(), "File not found");
+/// ```
+pub struct AnyhowError(anyhow::Error);
+
+impl AnyhowError {
+    /// Returns the wrapped `anyhow::Error`.
+    pub fn into_inner(self) -> anyhow::Error {
+        self.0
+    }
+}
+
+impl From<anyhow::Error> for AnyhowError {
+    fn from(err: anyhow::Error) -> Self {
+        Self(err)
+    }
+}
+
+impl AsRef<anyhow::Error> for AnyhowError {
+    fn as_ref(&self) -> &anyhow::Error {
+        &self.0
+    }
+}
+
+impl Debug for AnyhowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for AnyhowError {
+    /// Prints the outermost error of the chain,
+    /// or the entire chain if pretty-printed (`{:#}`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for AnyhowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl<F, M, I> IntoAnyhowResult<(), Error<I>> for ErrorStash<F, M, I>
+where
+    F: FnOnce() -> M,
+    M: Display,
+    Error<I>: IntoAnyhowError,
+{
+    #[track_caller]
+    fn into_anyhow_result(self) -> Result<(), anyhow::Error> {
+        let result: Result<(), Error<I>> = self.into();
+        result.map_err(IntoAnyhowError::into_anyhow_error)
+    }
+}
+
+impl<T, E> IntoAnyhowResult<T, E> for Result<T, E>
+where
+    E: IntoAnyhowError,
+{
+    fn into_anyhow_result(self) -> Result<T, anyhow::Error> {
+        self.map_err(IntoAnyhowError::into_anyhow_error)
+    }
+}
+
+impl<I> IntoAnyhowError for StashWithErrors<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn into_anyhow_error(self) -> anyhow::Error {
+        Error::<I>::from(self).into_anyhow_error()
+    }
+}
+
+impl<I> IntoAnyhowError for Error<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    fn into_anyhow_error(self) -> anyhow::Error {
+        anyhow::Error::new(self)
+    }
+}
+
+impl<I> IntoAnyhowError for StashedErrors<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    fn into_anyhow_error(self) -> anyhow::Error {
+        Error::from(ErrorData::Stashed(self)).into_anyhow_error()
+    }
+}
+
+impl<I> IntoAnyhowError for WrappedError<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    fn into_anyhow_error(self) -> anyhow::Error {
+        Error::from(ErrorData::Wrapped(self)).into_anyhow_error()
+    }
+}
+
+impl IntoAnyhowError for AdHocError {
+    fn into_anyhow_error(self) -> anyhow::Error {
+        let err: crate::prelude::Error = Error::from(ErrorData::AdHoc(self));
+        err.into_anyhow_error()
+    }
+}
+
+impl<F, M, I> IntoAnyhowResultLossy<(), Error<I>> for ErrorStash<F, M, I>
+where
+    F: FnOnce() -> M,
+    M: Display,
+    Error<I>: IntoAnyhowErrorLossy,
+{
+    #[track_caller]
+    fn into_anyhow_result_lossy(self) -> Result<(), anyhow::Error> {
+        let result: Result<(), Error<I>> = self.into();
+        result.map_err(IntoAnyhowErrorLossy::into_anyhow_error_lossy)
+    }
+}
+
+impl<T, E> IntoAnyhowResultLossy<T, E> for Result<T, E>
+where
+    E: IntoAnyhowErrorLossy,
+{
+    fn into_anyhow_result_lossy(self) -> Result<T, anyhow::Error> {
+        self.map_err(IntoAnyhowErrorLossy::into_anyhow_error_lossy)
+    }
+}
+
+impl<I: Display> IntoAnyhowErrorLossy for StashWithErrors<I> {
+    /// Flattens the error hierarchy into a single string
+    /// that is then passed to [`anyhow::anyhow!`].
+    #[track_caller]
+    fn into_anyhow_error_lossy(self) -> anyhow::Error {
+        Error::<I>::from(self).into_anyhow_error_lossy()
+    }
+}
+
+impl<I: Display> IntoAnyhowErrorLossy for Error<I> {
+    fn into_anyhow_error_lossy(self) -> anyhow::Error {
+        anyhow::anyhow!(format!("{self:#}"))
+    }
+}
+
+impl<I: Display> IntoAnyhowErrorLossy for StashedErrors<I> {
+    fn into_anyhow_error_lossy(self) -> anyhow::Error {
+        anyhow::anyhow!(format!("{self:#}"))
+    }
+}
+
+impl<I: Display> IntoAnyhowErrorLossy for WrappedError<I> {
+    fn into_anyhow_error_lossy(self) -> anyhow::Error {
+        anyhow::anyhow!(format!("{self:#}"))
+    }
+}
+
+impl IntoAnyhowErrorLossy for AdHocError {
+    fn into_anyhow_error_lossy(self) -> anyhow::Error {
+        anyhow::anyhow!(format!("{self:#}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use std::vec::Vec;
+
+    use crate::{prelude::*, AdHocError};
+
+    #[test]
+    fn chain_yields_wrapped_errors() {
+        let err: Error = Error::wrap_with("Inner error", "Context");
+        let chain: Vec<String> = err
+            .into_anyhow_error()
+            .chain()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(chain, ["Context: Inner error", "Inner error"]);
+    }
+
+    #[test]
+    fn ad_hoc_error_can_be_downcast_to_error() {
+        let err = AdHocError::from_message("Ad-hoc error").into_anyhow_error();
+        let err: &Error = err.downcast_ref().unwrap();
+        assert!(matches!(err.as_ref(), ErrorData::AdHoc(_)));
+    }
+}
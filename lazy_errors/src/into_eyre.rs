@@ -1,8 +1,9 @@
-use core::fmt::Display;
+use core::fmt::{Debug, Display};
 
 use crate::{
     error::{AdHocError, Error, ErrorData, StashedErrors, WrappedError},
     stash::{ErrorStash, StashWithErrors},
+    InnerError,
 };
 
 /// Adds the [`into_eyre_result`](Self::into_eyre_result) method
@@ -17,7 +18,7 @@ pub trait IntoEyreResult<T, I>
 where
     I: IntoEyreReport,
 {
-    /// Lossy conversion to return some type,
+    /// Lossless conversion to return some type,
     /// for example a list of errors that may be empty,
     /// from functions returning [`eyre::Result`],
     /// i.e. `Result<_, E>` where `E` is [`eyre::Report`]:
@@ -41,6 +42,133 @@ where
     ///     stash.into_eyre_result()
     /// }
     ///
+    /// let report: eyre::Report = parse_all().unwrap_err();
+    /// assert_eq!(format!("{report}"), "Failed to parse (3 errors)");
+    ///
+    /// let err: &Error = report.downcast_ref().unwrap();
+    /// assert_eq!(err.children().len(), 3);
+    /// ```
+    ///
+    /// Please take a look at [`IntoEyreReport`] for details.
+    /// If you'd rather have the [`eyre::Report`] print the entire tree
+    /// of errors via [`Display`], use [`IntoEyreResultLossy`] instead.
+    fn into_eyre_result(self) -> Result<T, eyre::Report>;
+}
+
+/// Adds the [`into_eyre_report`](Self::into_eyre_report) method
+/// on various error and error builder types.
+///
+/// Do not implement this trait. Importing the trait is sufficient.
+/// The trait is implemented on [`Error`], [`StashWithErrors`],
+/// [`StashedErrors`], [`WrappedError`], and [`AdHocError`]
+/// if the [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// allows the error to be put into an [`eyre::Report`]
+/// (i.e. if `I` implements [`InnerError`], [`Display`], [`Debug`],
+/// [`Send`], and [`Sync`], and is `'static`).
+pub trait IntoEyreReport {
+    /// Lossless conversion to return some type, for example
+    /// a non-empty list of one or more errors,
+    /// from functions returning [`eyre::Result`],
+    /// i.e. `Result<_, E>` where `E` is [`eyre::Report`].
+    ///
+    /// The error is converted into [`Error`] and then put into the
+    /// [`eyre::Report`] as-is. Thus, you can downcast the report
+    /// to get the original error back,
+    /// and [`eyre::Report::chain`] will yield the error and its
+    /// [`source`](core::error::Error::source)s.
+    /// However, [`eyre::Report`] prints the error in its short form
+    /// (for example, `Summary (2 errors)`) when printed via [`Display`].
+    /// Please take a look at [`IntoEyreReportLossy`]
+    /// if you'd rather have the report print the entire tree of errors.
+    /// [`AdHocError`]s are converted into the [`Error`] type
+    /// of the [`prelude`](crate::prelude).
+    ///
+    /// ```
+    /// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+    /// use eyre::bail;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn run() -> eyre::Result<()> {
+    ///     let mut stash = ErrorStash::new(|| "Failed to run");
+    ///     stash.push(Error::wrap_with("Inner error", "Context"));
+    ///     stash.push("Another error");
+    ///
+    ///     if let StashedResult::Err(errs) = stash.ok() {
+    ///         bail!(errs.take().into_eyre_report());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// let report: eyre::Report = run().unwrap_err();
+    /// let chain: Vec<String> = report
+    ///     .chain()
+    ///     .map(|err| err.to_string())
+    ///     .collect();
+    /// assert_eq!(chain, [
+    ///     "Failed to run (2 errors)",
+    ///     "Context: Inner error",
+    ///     "Inner error",
+    /// ]);
+    ///
+    /// let err: &Error = report.downcast_ref().unwrap();
+    /// let printed = format!("{err:#}");
+    /// let printed = replace_line_numbers(&printed);
+    /// assert_eq!(printed, indoc::indoc! {"
+    ///     Failed to run
+    ///     - Context: Inner error
+    ///       at src/into_eyre.rs:1234:56
+    ///       at src/into_eyre.rs:1234:56
+    ///     - Another error
+    ///       at src/into_eyre.rs:1234:56"});
+    /// ```
+    fn into_eyre_report(self) -> eyre::Report;
+}
+
+/// Adds the [`into_eyre_result_lossy`](Self::into_eyre_result_lossy) method
+/// on types that can be converted into `Result<T, E>`,
+/// converting them into `Result<T, eyre::Report>` instead.
+///
+/// Do not implement this trait. Importing the trait is sufficient
+/// due to blanket implementations. The trait is implemented on `R`
+/// if `R` can be converted into `Result<_, E>` and
+/// if `E` implements [`IntoEyreReportLossy`].
+///
+/// In contrast to [`IntoEyreResult`], the error is converted into a string.
+/// Thus, printing the [`eyre::Report`] via [`Display`] prints
+/// the entire tree of errors. However, the original error and its
+/// sources cannot be accessed anymore.
+/// This trait is also implemented if the
+/// [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// is not `'static` or does not implement `core::error::Error`.
+pub trait IntoEyreResultLossy<T, I>
+where
+    I: IntoEyreReportLossy,
+{
+    /// Lossy conversion to return some type,
+    /// for example a list of errors that may be empty,
+    /// from functions returning [`eyre::Result`],
+    /// i.e. `Result<_, E>` where `E` is [`eyre::Report`]:
+    ///
+    /// ```
+    /// use eyre::WrapErr;
+    /// use lazy_errors::prelude::*;
+    ///
+    /// fn parse(s: &str) -> eyre::Result<i32> {
+    ///     use core::str::FromStr;
+    ///     i32::from_str(s).wrap_err_with(|| format!("Not an i32: '{s}'"))
+    /// }
+    ///
+    /// fn parse_all() -> eyre::Result<()> {
+    ///     let mut stash = ErrorStash::new(|| "Failed to parse");
+    ///
+    ///     parse("🙈").or_stash(&mut stash);
+    ///     parse("🙉").or_stash(&mut stash);
+    ///     parse("🙊").or_stash(&mut stash);
+    ///
+    ///     stash.into_eyre_result_lossy()
+    /// }
+    ///
     /// let err: eyre::Report = parse_all().unwrap_err();
     /// let msg = format!("{err}");
     /// assert!(msg.contains("🙈"));
@@ -48,26 +176,35 @@ where
     /// assert!(msg.contains("🙊"));
     /// ```
     ///
-    /// Note: This method discards information because [`IntoEyreReport`]
+    /// Note: This method discards information because [`IntoEyreReportLossy`]
     /// flattens the type into a single string
     /// that is then passed to [`eyre::eyre!`].
     ///
     /// In some cases, for example if you're using [`or_create_stash`],
-    /// you may want to use [`IntoEyreReport`] instead.
+    /// you may want to use [`IntoEyreReportLossy`] instead.
+    /// If you want to keep the original error,
+    /// use [`IntoEyreResult`] instead.
     ///
     /// [`or_create_stash`]:
     /// crate::or_create_stash::OrCreateStash::or_create_stash
-    fn into_eyre_result(self) -> Result<T, eyre::Report>;
+    fn into_eyre_result_lossy(self) -> Result<T, eyre::Report>;
 }
 
-/// Adds the [`into_eyre_report`](Self::into_eyre_report) method
+/// Adds the [`into_eyre_report_lossy`](Self::into_eyre_report_lossy) method
 /// on various error and error builder types.
 ///
 /// Do not implement this trait. Importing the trait is sufficient.
-/// due to blanket implementations. The trait is implemented on
-/// [`StashWithErrors`] and on
-/// `E` if `E` implements `core::error::Error + Send + Sync + 'a`.
-pub trait IntoEyreReport {
+/// The trait is implemented on [`Error`], [`StashWithErrors`],
+/// [`StashedErrors`], [`WrappedError`], and [`AdHocError`].
+///
+/// In contrast to [`IntoEyreReport`], the error is converted into a string.
+/// Thus, printing the [`eyre::Report`] via [`Display`] prints
+/// the entire tree of errors. However, the original error and its
+/// sources cannot be accessed anymore.
+/// This trait is also implemented if the
+/// [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// is not `'static` or does not implement `core::error::Error`.
+pub trait IntoEyreReportLossy {
     /// Lossy conversion to return some type, for example
     /// a non-empty list of one or more errors,
     /// from functions returning [`eyre::Result`],
@@ -80,7 +217,7 @@ pub trait IntoEyreReport {
     ///
     /// fn adhoc_error() -> eyre::Result<()> {
     ///     let err = Error::from_message("first() failed");
-    ///     bail!(err.into_eyre_report());
+    ///     bail!(err.into_eyre_report_lossy());
     /// }
     ///
     /// let err: eyre::Report = adhoc_error().unwrap_err();
@@ -93,7 +230,7 @@ pub trait IntoEyreReport {
     /// fn wrapped_report() -> eyre::Result<()> {
     ///     let report = eyre!("This is an eyre::Report");
     ///     let err: Error = Error::wrap(report);
-    ///     bail!(err.into_eyre_report());
+    ///     bail!(err.into_eyre_report_lossy());
     /// }
     ///
     /// let err: eyre::Report = wrapped_report().unwrap_err();
@@ -109,7 +246,7 @@ pub trait IntoEyreReport {
     ///     adhoc_error().or_stash(&mut stash);
     ///     wrapped_report().or_stash(&mut stash);
     ///
-    ///     stash.into_eyre_result()
+    ///     stash.into_eyre_result_lossy()
     /// }
     ///
     /// let err: eyre::Report = stashed_errors().unwrap_err();
@@ -130,10 +267,12 @@ pub trait IntoEyreReport {
     /// that is then passed to [`eyre::eyre!`].
     ///
     /// In some cases, for example if you're using [`or_stash`],
-    /// you may want to use [`IntoEyreResult`] instead.
+    /// you may want to use [`IntoEyreResultLossy`] instead.
+    /// If you want to keep the original error,
+    /// use [`IntoEyreReport`] instead.
     ///
     /// [`or_stash`]: crate::or_stash::OrStash::or_stash
-    fn into_eyre_report(self) -> eyre::Report;
+    fn into_eyre_report_lossy(self) -> eyre::Report;
 }
 
 impl<F, M, I> IntoEyreResult<(), Error<I>> for ErrorStash<F, M, I>
@@ -149,7 +288,64 @@ where
     }
 }
 
-impl<I: Display> IntoEyreReport for StashWithErrors<I> {
+impl<I> IntoEyreReport for StashWithErrors<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn into_eyre_report(self) -> eyre::Report {
+        Error::<I>::from(self).into_eyre_report()
+    }
+}
+
+impl<I> IntoEyreReport for Error<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    fn into_eyre_report(self) -> eyre::Report {
+        eyre::Report::new(self)
+    }
+}
+
+impl<I> IntoEyreReport for StashedErrors<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    fn into_eyre_report(self) -> eyre::Report {
+        Error::from(ErrorData::Stashed(self)).into_eyre_report()
+    }
+}
+
+impl<I> IntoEyreReport for WrappedError<I>
+where
+    I: Display + Debug + InnerError + Send + Sync + 'static,
+{
+    fn into_eyre_report(self) -> eyre::Report {
+        Error::from(ErrorData::Wrapped(self)).into_eyre_report()
+    }
+}
+
+impl IntoEyreReport for AdHocError {
+    fn into_eyre_report(self) -> eyre::Report {
+        let err: crate::prelude::Error = Error::from(ErrorData::AdHoc(self));
+        err.into_eyre_report()
+    }
+}
+
+impl<F, M, I> IntoEyreResultLossy<(), Error<I>> for ErrorStash<F, M, I>
+where
+    F: FnOnce() -> M,
+    M: Display,
+    Error<I>: IntoEyreReportLossy,
+{
+    #[track_caller]
+    fn into_eyre_result_lossy(self) -> Result<(), eyre::Report> {
+        let result: Result<(), Error<I>> = self.into();
+        result.map_err(IntoEyreReportLossy::into_eyre_report_lossy)
+    }
+}
+
+impl<I: Display> IntoEyreReportLossy for StashWithErrors<I> {
     /// Flattens the error hierarchy into a single string
     /// that is then passed to [`eyre::eyre!`].
     ///
@@ -165,36 +361,72 @@ impl<I: Display> IntoEyreReport for StashWithErrors<I> {
     /// display the error using the regular, non-pretty-printed
     /// form and we won't see the full list of errors.
     #[track_caller]
-    fn into_eyre_report(self) -> eyre::Report {
+    fn into_eyre_report_lossy(self) -> eyre::Report {
         let err = Error::<I>::from(self);
         eyre::eyre!(format!("{err:#}"))
     }
 }
 
-impl<I: Display> IntoEyreReport for Error<I> {
-    fn into_eyre_report(self) -> eyre::Report {
+impl<I: Display> IntoEyreReportLossy for Error<I> {
+    fn into_eyre_report_lossy(self) -> eyre::Report {
         match self.into() {
-            ErrorData::Stashed(inner) => inner.into_eyre_report(),
-            ErrorData::Wrapped(inner) => inner.into_eyre_report(),
-            ErrorData::AdHoc(inner) => inner.into_eyre_report(),
+            ErrorData::Stashed(inner) => inner.into_eyre_report_lossy(),
+            ErrorData::Wrapped(inner) => inner.into_eyre_report_lossy(),
+            ErrorData::AdHoc(inner) => inner.into_eyre_report_lossy(),
         }
     }
 }
 
-impl<I: Display> IntoEyreReport for StashedErrors<I> {
-    fn into_eyre_report(self) -> eyre::Report {
+impl<I: Display> IntoEyreReportLossy for StashedErrors<I> {
+    fn into_eyre_report_lossy(self) -> eyre::Report {
         eyre::eyre!(format!("{self:#}"))
     }
 }
 
-impl<I: Display> IntoEyreReport for WrappedError<I> {
-    fn into_eyre_report(self) -> eyre::Report {
+impl<I: Display> IntoEyreReportLossy for WrappedError<I> {
+    fn into_eyre_report_lossy(self) -> eyre::Report {
         eyre::eyre!(format!("{self:#}"))
     }
 }
 
-impl IntoEyreReport for AdHocError {
-    fn into_eyre_report(self) -> eyre::Report {
+impl IntoEyreReportLossy for AdHocError {
+    fn into_eyre_report_lossy(self) -> eyre::Report {
         eyre::eyre!(format!("{self:#}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use std::vec::Vec;
+
+    use crate::{prelude::*, AdHocError};
+
+    #[test]
+    fn chain_yields_wrapped_and_stashed_errors() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push(Error::wrap_with("Inner error", "Context"));
+        errs.push("Another error");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let chain: Vec<String> = err
+            .into_eyre_report()
+            .chain()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(chain, [
+            "Summary (2 errors)",
+            "Context: Inner error",
+            "Inner error",
+        ]);
+    }
+
+    #[test]
+    fn ad_hoc_error_can_be_downcast_to_error() {
+        let report =
+            AdHocError::from_message("Ad-hoc error").into_eyre_report();
+        let err: &Error = report.downcast_ref().unwrap();
+        assert!(matches!(err.as_ref(), ErrorData::AdHoc(_)));
+    }
+}
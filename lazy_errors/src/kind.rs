@@ -0,0 +1,168 @@
+use core::{any::Any, fmt::Debug};
+
+use crate::Error;
+
+/// A user-defined type that classifies errors,
+/// for example to map errors to HTTP status codes or exit codes.
+///
+/// This trait is implemented for all types that are
+/// `Debug`, `Send`, `Sync`, and `'static`;
+/// you don't need to (and cannot) implement it yourself.
+///
+/// Kinds can be attached to [`AdHocError`]s, [`WrappedError`]s,
+/// and [`StashedErrors`] as well as to errors that are pushed into an
+/// [`ErrorStash`] via [`push_with_kind`].
+/// Usually, you'd attach a kind to an [`Error`] via [`Error::with_kind`]
+/// or to a `Result` via [`WithKind::with_kind`].
+/// Afterwards, you can search for kinds of a specific type
+/// in the entire tree of the error, for example via
+/// [`find_kind`](crate::ErrorData::find_kind) or
+/// [`most_severe_kind`](crate::ErrorData::most_severe_kind).
+/// Errors without kinds don't allocate any memory for kinds.
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// use core::str::FromStr;
+/// use lazy_errors::prelude::*;
+///
+/// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// enum Status {
+///     BadRequest = 400,
+///     NotFound = 404,
+///     Internal = 500,
+/// }
+///
+/// fn parse(input: &str) -> Result<u8, Error> {
+///     u8::from_str(input)
+///         .or_wrap_with(|| format!("Invalid input: '{input}'"))
+///         .with_kind(Status::BadRequest)
+/// }
+///
+/// let mut errs = ErrorStash::new(|| "Failed to handle request");
+/// let _ = parse("❌").or_stash(&mut errs);
+/// errs.push_with_kind("Database unavailable", Status::Internal);
+/// let not_found: Error = err!("No such user").with_kind(Status::NotFound);
+/// errs.push(not_found);
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// assert_eq!(err.find_kind::<Status>(), Some(&Status::BadRequest));
+/// assert_eq!(err.most_severe_kind::<Status>(), Some(&Status::Internal));
+/// assert_eq!(err.find_kind::<&str>(), None);
+/// # }
+/// ```
+///
+/// Note that kinds of nested errors can only be found
+/// if the [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// allows `lazy_errors` to identify them
+/// (see [`InnerError::as_error_tree`](crate::InnerError::as_error_tree)).
+///
+/// [`AdHocError`]: crate::AdHocError
+/// [`WrappedError`]: crate::WrappedError
+/// [`StashedErrors`]: crate::StashedErrors
+/// [`ErrorStash`]: crate::ErrorStash
+/// [`push_with_kind`]: crate::ErrorStash::push_with_kind
+pub trait ErrorKind: Any + Debug + Send + Sync {
+    /// Returns `self` as `&dyn Any`, so it can be downcast.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<K: Any + Debug + Send + Sync> ErrorKind for K {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Adds the [`with_kind`](Self::with_kind) method on
+/// `Result<_, Error<I>>`.
+///
+/// Do not implement this trait.
+/// Importing the trait is sufficient due to blanket implementations.
+pub trait WithKind<T, I> {
+    /// If `self` is `Result::Ok(value)`, returns `Result::Ok(value)`;
+    /// if `self` is `Result::Err(err)`, attaches the kind to `err`
+    /// as described in [`Error::with_kind`].
+    ///
+    /// Please take a look at [`ErrorKind`] for an example.
+    fn with_kind<K: ErrorKind>(self, kind: K) -> Result<T, Error<I>>;
+}
+
+impl<T, I> WithKind<T, I> for Result<T, Error<I>> {
+    fn with_kind<K: ErrorKind>(self, kind: K) -> Result<T, Error<I>> {
+        self.map_err(|err| err.with_kind(kind))
+    }
+}
+
+/// Returns `kind` as `K` if it is a value of type `K`.
+pub(crate) fn downcast_kind<K: ErrorKind>(kind: &dyn ErrorKind) -> Option<&K> {
+    kind.as_any().downcast_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    enum Severity {
+        Low,
+        High,
+    }
+
+    #[test]
+    fn error_without_kind_has_no_kind() {
+        let err: Error = Error::wrap("Error");
+        assert_eq!(err.find_kind::<Severity>(), None);
+        assert_eq!(err.most_severe_kind::<Severity>(), None);
+    }
+
+    #[test]
+    fn kind_of_root_node_is_found() {
+        let err: Error = Error::from_message("Error").with_kind(Severity::Low);
+        assert_eq!(err.find_kind::<Severity>(), Some(&Severity::Low));
+
+        let err: Error = Error::wrap("Error").with_kind(Severity::High);
+        assert_eq!(err.find_kind::<Severity>(), Some(&Severity::High));
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("Error");
+        let err: Error = errs
+            .into_result()
+            .with_kind(Severity::Low)
+            .unwrap_err();
+        assert_eq!(err.find_kind::<Severity>(), Some(&Severity::Low));
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn kinds_of_stashed_errors_are_found_in_order() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("Error without kind");
+        errs.push_with_kind("Error", Severity::High);
+        errs.push(Error::from_message("Error").with_kind(Severity::Low));
+        let err: Error = errs
+            .into_result()
+            .with_kind(Severity::Low)
+            .unwrap_err();
+
+        let kinds: alloc::vec::Vec<_> = err
+            .find_all_kinds::<Severity>()
+            .collect();
+        assert_eq!(kinds, [&Severity::Low, &Severity::High, &Severity::Low]);
+        assert_eq!(err.most_severe_kind::<Severity>(), Some(&Severity::High));
+    }
+
+    #[test]
+    fn kinds_of_other_types_are_ignored() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push_with_kind("Error", 404_u16);
+        let err: Error = errs.into_result().unwrap_err();
+
+        assert_eq!(err.find_kind::<u16>(), Some(&404));
+        assert_eq!(err.find_kind::<u32>(), None);
+        assert_eq!(err.find_kind::<Severity>(), None);
+    }
+}
@@ -1,5 +1,9 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(not(feature = "std"), no_std)]
+// Methods such as `or_stash` elide the lifetime of the `StashedResult`
+// they return. Newer compilers warn about that, older ones don't know
+// about this lint.
+#![allow(unknown_lints, mismatched_lifetime_syntaxes)]
 
 //! Effortlessly create, group, and nest arbitrary errors,
 //! and defer error handling ergonomically.
@@ -123,7 +127,36 @@
 //!     `core::error::Error`)
 //!   - Enable this flag if you're on Rust v1.80 or older (`core::error::Error`
 //!     was stabilized in Rust v1.81)
-//! - `eyre`: Adds `into_eyre_result` and `into_eyre_report` conversions
+//!   - Adds `Report` and `MainResult`, which print errors in a human-readable
+//!     way when returned from `main`
+//! - `anyhow`: Adds `into_anyhow_result` and `into_anyhow_error` conversions
+//!   (as well as their lossy variants) and `AnyhowError`, which allows stashing
+//!   `anyhow::Error`s without losing their chain of errors (implies `std`)
+//! - `backtrace`: Captures a `std::backtrace::Backtrace` when errors are
+//!   created, wrapped, or pushed into an [`ErrorStash`] (implies `std`)
+//!   - Backtraces are only captured if enabled by the `RUST_BACKTRACE` or
+//!     `RUST_LIB_BACKTRACE` environment variables
+//!   - Captured backtraces are printed when pretty-printing errors (`{:#}`)
+//! - `color`: Allows [`Renderer`] to highlight errors by ANSI escape codes,
+//!   respecting the `NO_COLOR` environment variable (implies `std`)
+//! - `eyre`: Adds `into_eyre_result` and `into_eyre_report` conversions (as
+//!   well as their lossy variants) and `EyreHandler`, which prints error trees
+//!   in `eyre::Report`s when installed via `eyre::set_hook`
+//! - `log`: Integrates with the `log` crate
+//!   - `ErrorData::log` emits a record for each leaf of an error, using the
+//!     key-value API of `log` for the context and source location
+//!   - `ErrorStash::with_logging` emits a record whenever an error is added
+//! - `sarif`: Adds `ErrorData::sarif`, which serializes the leaves of an error
+//!   as SARIF v2.1.0 log (implies `serde`)
+//! - `serde`: Implements `serde::Serialize` for the error types of this crate
+//!   (and for `StashWithErrors`), serializing error trees as nested structures,
+//!   and `serde::Deserialize` for [`OwnedError`]
+//! - `tracing`: Integrates with the `tracing` ecosystem (implies `std`)
+//!   - `ErrorStash::with_tracing` emits an event whenever an error is added
+//!   - `ErrorData::record_in_span` records an error in the fields of a span
+//!   - Captures a `tracing_error::SpanTrace` when errors are wrapped or pushed
+//!     into an [`ErrorStash`], which is printed when pretty-printing errors
+//!     (`{:#}`) if a `tracing_error::ErrorLayer` is installed
 //! - `rust-v$N` (where `$N` is a Rust version number): Add support for error
 //!   types from `core` and `alloc` that were stabilized in the respective Rust
 //!   version.
@@ -137,7 +170,10 @@
 //!   is greater than the version of your Rust toolchain. For example, to
 //!   compile `lazy_errors` on Rust v1.69, you have to disable `rust-v1.81` and
 //!   `rust-v1.77`, but not `rust-v1.69`.
-//! - `eyre` needs at least Rust v1.65
+//! - `eyre`, `backtrace`, and `tracing` need at least Rust v1.65
+//! - `anyhow` needs at least Rust v1.68
+//! - `color` needs at least Rust v1.70
+//! - `log` needs at least Rust v1.71
 //! - Rust versions older than v1.61 are unsupported
 //! - In Rust versions below v1.81, `core::error::Error` is not stable yet. If
 //!   you're using a Rust version before v1.81, please consider enabling the
@@ -534,17 +570,28 @@
 //! ### Example: `into_eyre_*`
 //!
 //! [`ErrorStash`] and [`StashWithErrors`] can be converted into
-//! [`Result`] and [`Error`], respectively. A similar, albeit lossy,
-//! conversion from [`ErrorStash`] and [`StashWithErrors`] exist for
+//! [`Result`] and [`Error`], respectively. Similar conversions
+//! from [`ErrorStash`] and [`StashWithErrors`] exist for
 //! `eyre::Result` and `eyre::Error` (i.e. `eyre::Report`), namely
 #![cfg_attr(
     not(feature = "eyre"),
-    doc = "`into_eyre_result` and `into_eyre_report`."
+    doc = "`into_eyre_result` and `into_eyre_report`.
+These conversions put the [`Error`] into the `eyre::Report` as-is,
+so you can downcast the report to get the original error back.
+If you'd rather have the `eyre::Report` print the entire tree of errors,
+use their lossy variants
+`into_eyre_result_lossy` and `into_eyre_report_lossy`."
 )]
 #![cfg_attr(
     feature = "eyre",
     doc = r##"[`into_eyre_result`](IntoEyreResult::into_eyre_result) and
-[`into_eyre_report`](IntoEyreReport::into_eyre_report):
+[`into_eyre_report`](IntoEyreReport::into_eyre_report).
+These conversions put the [`Error`] into the `eyre::Report` as-is,
+so you can downcast the report to get the original error back.
+If you'd rather have the `eyre::Report` print the entire tree of errors,
+use their lossy variants
+[`into_eyre_result_lossy`](IntoEyreResultLossy::into_eyre_result_lossy) and
+[`into_eyre_report_lossy`](IntoEyreReportLossy::into_eyre_report_lossy):
 
 ```
 # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
@@ -557,7 +604,7 @@ fn run() -> Result<(), eyre::Report> {
         Ok(()) => Ok(()),
         Err(mut stash) => {
             cleanup().or_stash(&mut stash);
-            bail!(stash.into_eyre_report());
+            bail!(stash.into_eyre_report_lossy());
         }
     }
 }
@@ -664,6 +711,9 @@ are compatible with other crates.
 //! You can chose the type to use for `I` arbitrarily.
 //! It can be a custom type and does not need to implement any traits
 //! or auto traits except [`Sized`].
+//! However, if you want to put `Error<I>` into _other_ containers
+//! that box their errors (such as the ones from the [`prelude`]),
+//! `I` needs to implement [`InnerError`].
 //! Thus, if the default aliases defined in the prelude
 //! do not suit your purpose, you can import the required traits
 //! and types manually and define custom aliases, as shown in the next example.
@@ -698,6 +748,10 @@ are compatible with other crates.
 //!     NotU32(&'a str),
 //! }
 //!
+//! // Allow putting `Error<CustomError>` into other stashes.
+//! // `CustomError` isn't `'static`, so it can't be exposed as error source.
+//! impl lazy_errors::InnerError for CustomError<'_> {}
+//!
 //! // Use `CustomError` as inner error type `I` for `ErrorStash`:
 //! type ParserErrorStash<'a, F, M> = ErrorStash<F, M, CustomError<'a>>;
 //!
@@ -860,33 +914,89 @@ pub mod prelude;
 
 pub mod surrogate_error_trait;
 
+mod attachments;
+mod diagnostic;
 mod err;
 mod error;
+mod fail_fast;
+mod github;
+mod inner_error;
+mod kind;
+mod location;
+#[cfg(feature = "log")]
+mod logging;
 mod or_create_stash;
 mod or_stash;
 mod or_wrap;
 mod or_wrap_with;
+mod owned;
+mod render;
+#[cfg(feature = "std")]
+mod report;
+mod retention;
+#[cfg(feature = "sarif")]
+mod sarif;
+#[cfg(feature = "serde")]
+mod serialize;
+mod severity;
 mod stash;
 mod stash_err;
+#[cfg(feature = "tracing")]
+mod trace;
 mod try2;
 mod try_collect_or_stash;
 mod try_map_or_stash;
+mod walk;
+mod warn;
 
-pub use error::{AdHocError, Error, ErrorData, StashedErrors, WrappedError};
+pub use attachments::{Attach, Attachments};
+pub use diagnostic::{Diagnostic, Diagnostics};
+pub use error::{
+    AdHocError, AdHocParts, Error, ErrorData, Location, StashedEntry,
+    StashedErrors, StashedParts, WrappedError, WrappedParts,
+};
+pub use fail_fast::FailFast;
+pub use github::GithubAnnotations;
+pub use inner_error::{DowncastInner, InnerError};
+pub use kind::{ErrorKind, WithKind};
+pub use location::ErrorLocation;
 pub use or_create_stash::OrCreateStash;
 pub use or_stash::{OrStash, StashedResult};
 pub use or_wrap::OrWrap;
 pub use or_wrap_with::OrWrapWith;
-pub use stash::{ErrorStash, StashWithErrors};
+pub use owned::{OwnedEntry, OwnedError, OwnedLeaf, OwnedLocation};
+pub use render::{Layout, Rendered, Renderer};
+#[cfg(feature = "std")]
+pub use report::{MainResult, Report};
+pub use retention::Retention;
+#[cfg(feature = "sarif")]
+pub use sarif::Sarif;
+pub use severity::Severity;
+pub use stash::{ErrorStash, StashOptions, StashWithErrors};
 pub use stash_err::{StashErr, StashErrIter};
 pub use surrogate_error_trait::Reportable;
 pub use try_collect_or_stash::TryCollectOrStash;
 pub use try_map_or_stash::TryMapOrStash;
+pub use walk::{Node, NodeKind, Walk};
+
+#[cfg(feature = "anyhow")]
+mod into_anyhow;
+#[cfg(feature = "anyhow")]
+pub use into_anyhow::{
+    AnyhowError, IntoAnyhowError, IntoAnyhowErrorLossy, IntoAnyhowResult,
+    IntoAnyhowResultLossy,
+};
 
+#[cfg(feature = "eyre")]
+mod eyre_handler;
 #[cfg(feature = "eyre")]
 mod into_eyre;
 #[cfg(feature = "eyre")]
-pub use into_eyre::{IntoEyreReport, IntoEyreResult};
+pub use eyre_handler::EyreHandler;
+#[cfg(feature = "eyre")]
+pub use into_eyre::{
+    IntoEyreReport, IntoEyreReportLossy, IntoEyreResult, IntoEyreResultLossy,
+};
 
 /// Alias of the `Result<T, E>` we all know, but uses
 /// [`prelude::Error`]
@@ -926,6 +1036,8 @@ pub type StashableImpl<'a> =
 /// Replaces parts of the string that maybe are a line number
 /// or maybe are a column number with static mock values.
 /// Also sneakly changes `\` to `/` because this may be a path separator.
+/// Also removes backtraces, which are printed only if enabled
+/// by the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables.
 ///
 /// We just need this method to be able to use [`assert_eq`] in doctests.
 /// This function may behave incorrectly in many cases.
@@ -939,8 +1051,7 @@ pub fn doctest_line_num_helper(text: &str) -> alloc::string::String {
     // Using a regex would require us to add the regex crate
     // as dependency in general.
 
-    #[allow(clippy::useless_format)] // `use` would break MSRV
-    let mut result = format!("{text}");
+    let mut result = strip_backtraces(text);
     loop {
         let result_before = result.clone();
         for i in 0..=9 {
@@ -956,6 +1067,42 @@ pub fn doctest_line_num_helper(text: &str) -> alloc::string::String {
 
     result
         .replace('\\', "/")
-        .replace("at lazy_errors/src/", "at src/")
+        .replace("lazy_errors/src/", "src/")
         .replace(".rs::", ".rs:1234:56")
 }
+
+/// Removes all lines that have been printed by
+/// `error::display_backtrace` from the string.
+///
+/// Each backtrace starts with a `stack backtrace:` line.
+/// All lines of the backtrace itself are indented further
+/// than that line, using the same prefix.
+pub(crate) fn strip_backtraces(text: &str) -> alloc::string::String {
+    let mut result = alloc::string::String::with_capacity(text.len());
+    let mut backtrace_prefix: Option<&str> = None;
+    for line in text.split_inclusive('\n') {
+        if let Some(prefix) = backtrace_prefix {
+            match line.strip_prefix(prefix) {
+                Some(rest) if rest.starts_with(' ') => continue,
+                _ => backtrace_prefix = None,
+            }
+        }
+
+        let content = line.trim_end_matches(['\r', '\n']);
+        if let Some(prefix) = content.strip_suffix("stack backtrace:") {
+            backtrace_prefix = Some(prefix);
+            continue;
+        }
+
+        result.push_str(line);
+    }
+
+    // If the text ended with a backtrace, it now ends with the newline
+    // that had separated the backtrace from the preceding line.
+    if backtrace_prefix.is_none() || text.ends_with('\n') {
+        return result;
+    }
+    result
+        .trim_end_matches(['\r', '\n'])
+        .into()
+}
@@ -0,0 +1,117 @@
+use core::fmt::{self, Display};
+
+use alloc::boxed::Box;
+
+use crate::{Location, OwnedLocation};
+
+/// The source location of a node in the tree of an [`Error`],
+/// i.e. the location at which an error was created, wrapped, or stashed.
+///
+/// Usually, `lazy_errors` records source locations implicitly via
+/// `#[track_caller]`, in which case the `ErrorLocation` is just the
+/// recorded [`Location`]. When an [`Error`] is reconstructed
+/// from an [`OwnedError`], for example after receiving it
+/// from another process, the original source locations are restored
+/// from [`OwnedLocation`]s instead.
+/// Regardless of how an `ErrorLocation` was created,
+/// it will always be printed as `file:line:column`:
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// use lazy_errors::{
+///     prelude::*, ErrorLocation, OwnedError, OwnedLocation, Severity,
+/// };
+///
+/// let location = OwnedLocation {
+///     file:   String::from("src/remote.rs"),
+///     line:   42,
+///     column: 7,
+/// };
+///
+/// let err: Error = Error::from(OwnedError::AdHoc {
+///     message:  String::from("Remote error"),
+///     fields:   vec![],
+///     location: location.clone(),
+///     severity: Severity::Error,
+/// });
+///
+/// let ad_hoc = match err.as_ref() {
+///     ErrorData::AdHoc(err) => err,
+///     _ => unreachable!(),
+/// };
+///
+/// assert_eq!(ad_hoc.location(), &ErrorLocation::from(location));
+/// assert_eq!(ad_hoc.location().file(), "src/remote.rs");
+/// assert_eq!(ad_hoc.location().to_string(), "src/remote.rs:42:7");
+///
+/// let location = ErrorLocation::from(core::panic::Location::caller());
+/// assert_eq!(location.file(), file!());
+/// assert_eq!(location.line(), line!() - 2);
+/// # }
+/// ```
+///
+/// [`Error`]: crate::Error
+/// [`OwnedError`]: crate::OwnedError
+#[derive(Clone, Debug)]
+pub enum ErrorLocation {
+    /// The location was recorded via `#[track_caller]`.
+    Recorded(Location),
+
+    /// The location was restored from an [`OwnedLocation`].
+    Owned(Box<OwnedLocation>),
+}
+
+impl ErrorLocation {
+    /// Returns the name of the source file.
+    pub fn file(&self) -> &str {
+        match self {
+            Self::Recorded(location) => location.file(),
+            Self::Owned(location) => &location.file,
+        }
+    }
+
+    /// Returns the line number in the source file.
+    pub fn line(&self) -> u32 {
+        match self {
+            Self::Recorded(location) => location.line(),
+            Self::Owned(location) => location.line,
+        }
+    }
+
+    /// Returns the column number in the source file.
+    pub fn column(&self) -> u32 {
+        match self {
+            Self::Recorded(location) => location.column(),
+            Self::Owned(location) => location.column,
+        }
+    }
+}
+
+impl From<Location> for ErrorLocation {
+    fn from(location: Location) -> Self {
+        Self::Recorded(location)
+    }
+}
+
+impl From<OwnedLocation> for ErrorLocation {
+    fn from(location: OwnedLocation) -> Self {
+        Self::Owned(Box::new(location))
+    }
+}
+
+impl PartialEq for ErrorLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.file() == other.file()
+            && self.line() == other.line()
+            && self.column() == other.column()
+    }
+}
+
+impl Eq for ErrorLocation {}
+
+impl Display for ErrorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file(), self.line(), self.column())
+    }
+}
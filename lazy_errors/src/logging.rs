@@ -0,0 +1,297 @@
+use core::fmt::{self, Display};
+
+use alloc::string::String;
+
+use log::{kv::Value, Level, Metadata, Record};
+
+use crate::{
+    Diagnostic, ErrorData, ErrorLocation, InnerError, Location, Severity,
+};
+
+/// The target of records of errors that have no source location.
+const FALLBACK_TARGET: &str = "lazy_errors";
+
+/// Prints the summaries and contexts of a [`Diagnostic`],
+/// separated by `: `, when printed via [`Display`].
+struct Context<'a>(&'a [&'a str]);
+
+/// Prints the message of a [`Diagnostic`] when printed via [`Display`].
+struct Message<'a, 'b, I>(&'a Diagnostic<'b, I>);
+
+/// Emits a record for an error that is added to a stash
+/// with the given `summary`.
+///
+/// Used as hook that is set by `with_logging`.
+pub(crate) fn log_push<I: Display>(
+    summary: &str,
+    error: &I,
+    location: Location,
+    severity: Severity,
+    level: Level,
+) {
+    let location = ErrorLocation::from(location);
+    let level = level_of(level, severity);
+
+    let kvs = [
+        ("summary", Value::from_display(&summary)),
+        ("location", Value::from_display(&location)),
+    ];
+
+    emit(level, Some(&location), format_args!("{error}"), &kvs);
+}
+
+/// Emits a record for each leaf in the tree of `error`.
+pub(crate) fn log<I>(error: &ErrorData<I>, level: Level)
+where
+    I: Display + InnerError,
+{
+    for diagnostic in error.diagnostics() {
+        let level = level_of(level, diagnostic.severity());
+        let location = diagnostic.location();
+        let context = Context(diagnostic.context());
+        let severity = diagnostic.severity();
+
+        let context = ("context", Value::from_display(&context));
+        let severity = ("severity", Value::from_display(&severity));
+        let message = Message(&diagnostic);
+
+        match location {
+            Some(location) => {
+                let location_kv = ("location", Value::from_display(&location));
+                let kvs = [context, severity, location_kv];
+                emit(level, Some(location), format_args!("{message}"), &kvs);
+            }
+            None => {
+                let kvs = [context, severity];
+                emit(level, None, format_args!("{message}"), &kvs);
+            }
+        }
+    }
+}
+
+/// Returns `level`, or `Level::Warn` if `level` is more severe
+/// and the error has been added as [`Severity::Warning`].
+fn level_of(level: Level, severity: Severity) -> Level {
+    match severity {
+        Severity::Warning => Ord::max(level, Level::Warn),
+        Severity::Error => level,
+    }
+}
+
+/// Derives the target of a record from the source file of an error,
+/// turning paths such as `src/parser/lexer.rs` into `src::parser::lexer`,
+/// so that loggers can filter records in the same way
+/// they filter records by module path.
+fn target(file: &str) -> String {
+    file.strip_suffix(".rs")
+        .unwrap_or(file)
+        .replace(['/', '\\'], "::")
+}
+
+fn emit(
+    level: Level,
+    location: Option<&ErrorLocation>,
+    args: fmt::Arguments<'_>,
+    kvs: &[(&str, Value<'_>)],
+) {
+    if level > log::max_level() {
+        return;
+    }
+
+    let target = match location {
+        Some(location) => target(location.file()),
+        None => String::from(FALLBACK_TARGET),
+    };
+
+    let metadata = Metadata::builder()
+        .level(level)
+        .target(&target)
+        .build();
+
+    let logger = log::logger();
+    if !logger.enabled(&metadata) {
+        return;
+    }
+
+    logger.log(
+        &Record::builder()
+            .metadata(metadata)
+            .args(args)
+            .file(location.map(ErrorLocation::file))
+            .line(location.map(ErrorLocation::line))
+            .key_values(&kvs)
+            .build(),
+    );
+}
+
+impl Display for Context<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, context) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ": ")?;
+            }
+            write!(f, "{context}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<I: Display> Display for Message<'_, '_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.display_message(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::RefCell;
+    use std::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use log::{
+        kv::{self, Key, Value, VisitSource},
+        Level, LevelFilter, Log, Metadata, Record,
+    };
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    /// Records all records of the current thread
+    /// as `level target: message` line followed by `key=value` lines.
+    struct Recorder;
+
+    /// Records the key-value pairs of a record as `key=value` strings.
+    struct KeyValues<'a>(&'a mut Vec<String>);
+
+    std::thread_local! {
+        static RECORDS: RefCell<Vec<String>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    impl Log for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            RECORDS.with(|records| {
+                let mut records = records.borrow_mut();
+                records.push(format!(
+                    "{} {}: {}",
+                    record.level(),
+                    record.target(),
+                    record.args()
+                ));
+
+                let mut visitor = KeyValues(&mut records);
+                record
+                    .key_values()
+                    .visit(&mut visitor)
+                    .unwrap();
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    impl<'kvs> VisitSource<'kvs> for KeyValues<'_> {
+        fn visit_pair(
+            &mut self,
+            key: Key<'kvs>,
+            value: Value<'kvs>,
+        ) -> Result<(), kv::Error> {
+            self.0.push(format!("{key}={value}"));
+            Ok(())
+        }
+    }
+
+    /// Returns all records that have been emitted by `f`.
+    fn record<F: FnOnce()>(f: F) -> Vec<String> {
+        // Fails if the logger has been set by another test already.
+        let _ = log::set_logger(&Recorder);
+        log::set_max_level(LevelFilter::Trace);
+
+        f();
+        RECORDS.with(|records| core::mem::take(&mut *records.borrow_mut()))
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn log_emits_record_per_leaf() {
+        let mut inner = ErrorStash::new(|| "Failed to parse config");
+        inner.push("Unexpected token");
+        inner.push_warning(Error::from_message("Key 'colour' is deprecated"));
+        let inner: Error = inner.into_result().unwrap_err();
+
+        let mut errs = ErrorStash::new(|| "Failed to start");
+        errs.push(Error::wrap_with(inner, "In 'config.toml'"));
+        let err: Error = errs.into_result().unwrap_err();
+
+        let locations: Vec<_> = err
+            .diagnostics()
+            .filter_map(|diagnostic| diagnostic.location())
+            .collect();
+
+        assert_eq!(locations.len(), 2);
+        let (first, second) = (locations[0], locations[1]);
+        let target = super::target(file!());
+
+        let records = record(|| err.log(Level::Error));
+        assert_eq!(records, [
+            format!("ERROR {target}: Unexpected token"),
+            "context=Failed to start: In 'config.toml': Failed to parse config"
+                .to_string(),
+            "severity=error".to_string(),
+            format!("location={first}"),
+            format!("WARN {target}: Key 'colour' is deprecated"),
+            "context=Failed to start: In 'config.toml': Failed to parse config"
+                .to_string(),
+            "severity=warning".to_string(),
+            format!("location={second}"),
+        ]);
+    }
+
+    #[test]
+    fn push_emits_record_if_enabled() {
+        let records = record(|| {
+            let mut errs = ErrorStash::new(|| "Summary");
+            errs.push("Silent error");
+        });
+        assert!(records.is_empty());
+
+        let mut locations = Vec::new();
+        let records = record(|| {
+            let mut errs =
+                ErrorStash::new(|| "Summary").with_logging(Level::Error);
+            errs.push("First error");
+            let errs = errs.push_warning(Error::from_message("Second error"));
+            locations.extend_from_slice(errs.locations());
+        });
+
+        assert_eq!(locations.len(), 2);
+        let (first, second) = (&locations[0], &locations[1]);
+        let target = super::target(file!());
+
+        assert_eq!(records, [
+            format!("ERROR {target}: First error"),
+            "summary=Summary".to_string(),
+            format!("location={first}"),
+            format!("WARN {target}: Second error"),
+            "summary=Summary".to_string(),
+            format!("location={second}"),
+        ]);
+    }
+
+    #[test]
+    fn target_is_derived_from_file() {
+        assert_eq!(super::target("src/parser/lexer.rs"), "src::parser::lexer");
+        assert_eq!(super::target("src\\main.rs"), "src::main");
+        assert_eq!(super::target("build"), "build");
+    }
+}
@@ -29,6 +29,15 @@ pub trait OrStash<S, I, T> {
     /// adds `e` to the provided [`ErrorStash`] or [`StashWithErrors`]
     /// and returns the `Err` variant [`StashedResult`].
     ///
+    /// If the stash has a [`FailFast`] policy,
+    /// this method returns the `LimitReached` variant instead of `Err`
+    /// once the limit of that policy has been reached.
+    /// If `self` is `Result::Ok(value)`, this method always returns
+    /// `Ok(value)`, even if the limit has been reached before,
+    /// so that `value` does not get lost.
+    /// Call `is_limit_reached` on the stash if you need to stop
+    /// processing before the next `Err` is encountered.
+    ///
     /// Use this method to collect an arbitrary number
     /// of `Result::Err` occurrences
     /// in an [`ErrorStash`] or a [`StashWithErrors`],
@@ -104,8 +113,51 @@ pub trait OrStash<S, I, T> {
     /// to create a non-empty container on-demand, whenever necessary.
     ///
     /// [`ErrorStash`]: crate::ErrorStash
+    /// [`FailFast`]: crate::FailFast
     /// [`or_create_stash`]: crate::OrCreateStash::or_create_stash
     fn or_stash(self, stash: &mut S) -> StashedResult<T, I>;
+
+    /// If `self` is `Result::Ok(value)`, returns `Some(value)`;
+    /// if `self` is `Result::Err(e)`, adds `e` to the provided
+    /// [`ErrorStash`] or [`StashWithErrors`] as
+    /// [`Severity::Warning`] and returns `None`.
+    ///
+    /// Warnings are printed along with all other errors in the stash,
+    /// but they do not cause the stash to fail.
+    /// Thus, as opposed to [`or_stash`](OrStash::or_stash),
+    /// this method does not return a [`StashedResult`]
+    /// that could be passed to [`try2!`].
+    /// Please take a look at [`Severity`] for details.
+    ///
+    /// ```
+    /// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// use lazy_errors::prelude::*;
+    ///
+    /// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    /// use lazy_errors::surrogate_error_trait::prelude::*;
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to read settings");
+    ///
+    /// let width: u8 = "80"
+    ///     .parse()
+    ///     .or_stash_warning(&mut errs)
+    ///     .unwrap_or(80);
+    /// let height: u8 = "ab"
+    ///     .parse()
+    ///     .or_stash_warning(&mut errs)
+    ///     .unwrap_or(24);
+    /// assert_eq!((width, height), (80, 24));
+    ///
+    /// assert!(!errs.is_empty());
+    /// assert!(!errs.has_errors());
+    /// assert!(errs.into_result().is_ok());
+    /// ```
+    ///
+    /// [`ErrorStash`]: crate::ErrorStash
+    /// [`Severity`]: crate::Severity
+    /// [`Severity::Warning`]: crate::Severity::Warning
+    /// [`try2!`]: crate::try2!
+    fn or_stash_warning(self, stash: &mut S) -> Option<T>;
 }
 
 /// Similar to [`core::result::Result`], except that this type
@@ -131,13 +183,20 @@ pub trait OrStash<S, I, T> {
 /// `StashedResult` is returned from [`or_stash`].
 /// There should be no need to create values of this type manually.
 ///
+/// If the stash has a [`FailFast`] policy,
+/// [`or_stash`] returns `LimitReached` instead of `Err`
+/// once the limit of that policy has been reached.
+/// [`try2!`] treats `LimitReached` the same way as `Err`.
+///
 /// [`ErrorStash`]: crate::ErrorStash
+/// [`FailFast`]: crate::FailFast
 /// [`try2!`]: crate::try2!
 /// [`or_stash`]: OrStash::or_stash
 #[derive(Debug)]
 pub enum StashedResult<'s, T, I> {
     Ok(T),
     Err(&'s mut StashWithErrors<I>),
+    LimitReached(&'s mut StashWithErrors<I>),
 }
 
 impl<T, E, S, I> OrStash<S, I, T> for Result<T, E>
@@ -148,8 +207,38 @@ where
     #[track_caller]
     fn or_stash(self, stash: &mut S) -> StashedResult<T, I> {
         match self {
-            Ok(v) => StashedResult::Ok(v),
-            Err(err) => StashedResult::Err(stash.stash(err)),
+            Ok(v) => {
+                stash.record_success();
+                StashedResult::Ok(v)
+            }
+            Err(err) => StashedResult::failed(stash.stash(err)),
+        }
+    }
+
+    #[track_caller]
+    fn or_stash_warning(self, stash: &mut S) -> Option<T> {
+        match self {
+            Ok(v) => {
+                stash.record_success();
+                Some(v)
+            }
+            Err(err) => {
+                stash.stash_warning(err);
+                None
+            }
+        }
+    }
+}
+
+impl<'s, T, I> StashedResult<'s, T, I> {
+    /// Returns `LimitReached` if the limit of the [`FailFast`] policy
+    /// of `errs` has been reached, `Err` otherwise.
+    ///
+    /// [`FailFast`]: crate::FailFast
+    pub(crate) fn failed(errs: &'s mut StashWithErrors<I>) -> Self {
+        match errs.is_limit_reached() {
+            true => StashedResult::LimitReached(errs),
+            false => StashedResult::Err(errs),
         }
     }
 }
@@ -215,7 +304,7 @@ impl<T, E> StashedResult<'_, T, E> {
     pub fn ok(self) -> Option<T> {
         match self {
             StashedResult::Ok(t) => Some(t),
-            StashedResult::Err(_) => None,
+            StashedResult::Err(_) | StashedResult::LimitReached(_) => None,
         }
     }
 }
@@ -0,0 +1,712 @@
+#[cfg(feature = "rust-v1.81")]
+use core::error::Error as StdError;
+
+#[cfg(all(not(feature = "rust-v1.81"), feature = "std"))]
+use std::error::Error as StdError;
+
+use core::fmt::{self, Display};
+
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    error::Extras, retention::Omitted, AdHocError, Attachments, Error,
+    ErrorData, ErrorLocation, InnerError, Location, NodeKind, Severity,
+    StashedErrors, WrappedError,
+};
+
+/// An owned, `'static` copy of the entire tree of an [`Error`],
+/// which can be sent to other threads or processes
+/// and then be turned back into an [`Error`].
+///
+/// `OwnedError` can be created from any [`Error`] (or [`ErrorData`])
+/// whose [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// implements [`Display`] and [`InnerError`].
+/// Errors from this crate are copied as-is, while all other inner errors
+/// are copied as [`OwnedLeaf`], i.e. by their [`Display`] representation.
+/// Source locations (as [`OwnedLocation`]), the fields of [`Attachments`],
+/// the number of errors that have been dropped because of the
+/// [`Retention`](crate::Retention) of a stash, and whether a stash
+/// has been aborted early are preserved as well,
+/// but backtraces, [`ErrorKind`]s, and typed attachments are not.
+///
+/// If the `serde` feature is enabled, `OwnedError` implements
+/// `Serialize` and `Deserialize`. The format is the same that is used
+/// when serializing [`Error`] directly. Thus, you can serialize an [`Error`]
+/// in one process and deserialize it as `OwnedError` in another process.
+///
+/// An `OwnedError` can be turned back into an [`Error`].
+/// The reconstructed [`Error`] will print the same messages and
+/// source locations as the original error, both in regular and
+/// in “pretty” (`{:#}`) format (except for backtraces):
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// use lazy_errors::{prelude::*, OwnedError};
+///
+/// let mut errs = ErrorStash::new(|| "Failed to run");
+/// errs.push(Error::wrap_with("Inner error", "Context"));
+/// errs.push(Error::from_message("Ad-hoc error"));
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let owned = OwnedError::from(&err);
+/// let thread = std::thread::spawn(move || -> Error { Error::from(owned) });
+/// let copy = thread.join().unwrap();
+///
+/// assert_eq!(format!("{copy}"), format!("{err}"));
+/// # let copy = replace_line_numbers(&format!("{copy:#}"));
+/// # let err = replace_line_numbers(&format!("{err:#}"));
+/// # assert_eq!(copy, err);
+/// # }
+/// ```
+///
+/// [`Attachments`]: crate::Attachments
+/// [`ErrorKind`]: crate::ErrorKind
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", rename_all = "snake_case")
+)]
+pub enum OwnedError {
+    /// Owned copy of [`StashedErrors`](crate::StashedErrors).
+    Stashed {
+        summary: String,
+
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        fields: Vec<(String, String)>,
+
+        errors: Vec<OwnedEntry>,
+
+        /// The number of errors that have been dropped from the stash
+        /// because of its [`Retention`](crate::Retention).
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "is_zero")
+        )]
+        omitted_errors: usize,
+
+        /// The number of warnings that have been dropped from the stash
+        /// because of its [`Retention`](crate::Retention).
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "is_zero")
+        )]
+        omitted_warnings: usize,
+
+        /// Whether processing has been aborted early because of the
+        /// [`FailFast`](crate::FailFast) policy of the stash.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "core::ops::Not::not")
+        )]
+        aborted: bool,
+    },
+
+    /// Owned copy of [`WrappedError`].
+    Wrapped {
+        context: Option<String>,
+
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        fields: Vec<(String, String)>,
+
+        location: OwnedLocation,
+        inner:    Box<OwnedError>,
+    },
+
+    /// Owned copy of [`AdHocError`].
+    AdHoc {
+        message: String,
+
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Vec::is_empty")
+        )]
+        fields: Vec<(String, String)>,
+
+        location: OwnedLocation,
+
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Severity::is_error")
+        )]
+        severity: Severity,
+    },
+
+    /// Owned copy of an inner error that is not
+    /// (or could not be identified as) an error from this crate.
+    Leaf(OwnedLeaf),
+}
+
+/// An error in the list of errors of [`OwnedError::Stashed`],
+/// along with the source location at which it was added to the list,
+/// the fields that were attached to it at that time,
+/// and the [`Severity`] with which it was added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedEntry {
+    pub location: OwnedLocation,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fields: Vec<(String, String)>,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Severity::is_error")
+    )]
+    pub severity: Severity,
+
+    pub error: OwnedError,
+}
+
+/// Owned copy of an [`ErrorLocation`], i.e. the name of the source file
+/// and the line and column numbers.
+///
+/// When an [`Error`] is reconstructed from an [`OwnedError`],
+/// each node of the reconstructed tree keeps its original source location
+/// as [`ErrorLocation::Owned`]:
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// use lazy_errors::{prelude::*, OwnedError, OwnedLocation, Severity};
+///
+/// let location = OwnedLocation {
+///     file:   String::from("src/remote.rs"),
+///     line:   42,
+///     column: 7,
+/// };
+///
+/// let err: Error = Error::from(OwnedError::AdHoc {
+///     message:  String::from("Remote error"),
+///     fields:   vec![],
+///     location: location.clone(),
+///     severity: Severity::Error,
+/// });
+///
+/// assert_eq!(format!("{err:#}"), "Remote error\nat src/remote.rs:42:7");
+///
+/// let ad_hoc = match err.as_ref() {
+///     ErrorData::AdHoc(err) => err,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(ad_hoc.location().file(), "src/remote.rs");
+/// assert_eq!(OwnedLocation::from(ad_hoc.location()), location);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedLocation {
+    pub file:   String,
+    pub line:   u32,
+    pub column: u32,
+}
+
+impl From<Location> for OwnedLocation {
+    fn from(location: Location) -> Self {
+        Self {
+            file:   location.file().to_string(),
+            line:   location.line(),
+            column: location.column(),
+        }
+    }
+}
+
+impl Display for OwnedLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+impl From<&ErrorLocation> for OwnedLocation {
+    fn from(location: &ErrorLocation) -> Self {
+        match location {
+            ErrorLocation::Recorded(location) => Self::from(*location),
+            ErrorLocation::Owned(location) => OwnedLocation::clone(location),
+        }
+    }
+}
+
+/// Owned copy of an inner error that is not
+/// (or could not be identified as) an error from this crate.
+///
+/// `OwnedLeaf` stores the messages that the original error printed
+/// in regular and in “pretty” (`{:#}`) format,
+/// as well as the messages of its `core::error::Error::source`s.
+/// When printed, `OwnedLeaf` prints the stored messages.
+/// Note that the sources are not returned from `source` because
+/// the original error types have been lost. Use [`OwnedLeaf::sources`]
+/// to access their messages instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedLeaf {
+    message: String,
+
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    alternate: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    sources: Vec<String>,
+}
+
+impl OwnedLeaf {
+    /// Returns the message that the original error printed
+    /// in regular format.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the messages of the chain of errors that were returned by
+    /// `core::error::Error::source` of the original error,
+    /// excluding the original error itself.
+    ///
+    /// This list is empty if the original error could not be viewed
+    /// as `core::error::Error` (see `InnerError::as_error_source`).
+    pub fn sources(&self) -> &[String] {
+        &self.sources
+    }
+
+    pub(crate) fn from_inner<I>(err: &I) -> Self
+    where
+        I: Display + InnerError,
+    {
+        let message = err.to_string();
+        let alternate = format!("{err:#}");
+        let alternate = if alternate != message {
+            Some(alternate)
+        } else {
+            None
+        };
+
+        Self {
+            message,
+            alternate,
+            sources: sources(err),
+        }
+    }
+}
+
+impl Display for OwnedLeaf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_pretty = f.alternate(); // `#` in format string
+        match (&self.alternate, is_pretty) {
+            (Some(alternate), true) => write!(f, "{alternate}"),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+impl StdError for OwnedLeaf {}
+
+impl<I> From<&Error<I>> for OwnedError
+where
+    I: Display + InnerError,
+{
+    fn from(err: &Error<I>) -> Self {
+        let data: &ErrorData<I> = err;
+        Self::from(data)
+    }
+}
+
+impl<I> From<&ErrorData<I>> for OwnedError
+where
+    I: Display + InnerError,
+{
+    fn from(err: &ErrorData<I>) -> Self {
+        Self::from(NodeKind::from(err))
+    }
+}
+
+impl<I> From<NodeKind<'_, I>> for OwnedError
+where
+    I: Display + InnerError,
+{
+    fn from(node: NodeKind<'_, I>) -> Self {
+        match node {
+            NodeKind::Stashed(errs) => Self::Stashed {
+                summary: errs.summary().to_string(),
+                fields: fields(Some(errs.attachments())),
+                errors: errs
+                    .errors_with_locations()
+                    .enumerate()
+                    .map(|(i, (error, location))| OwnedEntry {
+                        location: location.into(),
+                        fields:   fields(errs.entry_attachments(i)),
+                        severity: errs.severity(i),
+                        error:    Self::from_inner(error),
+                    })
+                    .collect(),
+                omitted_errors: errs.omitted().errors,
+                omitted_warnings: errs.omitted().warnings,
+                aborted: errs.is_aborted(),
+            },
+            NodeKind::Wrapped(err) => Self::Wrapped {
+                context:  err.context().map(ToString::to_string),
+                fields:   fields(Some(err.attachments())),
+                location: err.location().into(),
+                inner:    Box::new(Self::from_inner(err.inner())),
+            },
+            NodeKind::AdHoc(err) => Self::AdHoc {
+                message:  err.message().to_string(),
+                fields:   fields(Some(err.attachments())),
+                location: err.location().into(),
+                severity: err.severity(),
+            },
+            NodeKind::Leaf(err) => Self::Leaf(OwnedLeaf::from_inner(err)),
+        }
+    }
+}
+
+impl OwnedError {
+    fn from_inner<I>(err: &I) -> Self
+    where
+        I: Display + InnerError,
+    {
+        err.as_error_tree()
+            .map(Self::from)
+            .unwrap_or_else(|| Self::Leaf(OwnedLeaf::from_inner(err)))
+    }
+
+    fn into_inner<I>(self) -> I
+    where
+        OwnedLeaf: Into<I>,
+        Error<I>: Into<I>,
+    {
+        match self {
+            Self::Leaf(leaf) => leaf.into(),
+            err => Error::from(err).into(),
+        }
+    }
+}
+
+/// Reconstructs an [`Error`] from an [`OwnedError`].
+///
+/// Children of the tree are converted into the
+/// [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// either from [`OwnedLeaf`] or from a reconstructed [`Error<I>`].
+///
+/// The nodes of the reconstructed tree keep their original
+/// source locations as [`ErrorLocation::Owned`].
+///
+/// Since [`Error`] cannot represent an [`OwnedError::Leaf`] directly,
+/// a leaf at the root of the tree will be wrapped,
+/// using the location at which `from` was called.
+impl<I> From<OwnedError> for Error<I>
+where
+    OwnedLeaf: Into<I>,
+    Error<I>: Into<I>,
+{
+    #[track_caller]
+    fn from(err: OwnedError) -> Self {
+        let (data, fields) = match err {
+            OwnedError::Stashed {
+                summary,
+                fields,
+                errors,
+                omitted_errors,
+                omitted_warnings,
+                aborted,
+            } => {
+                let mut locations = Vec::with_capacity(errors.len());
+                let mut extras = Vec::with_capacity(errors.len());
+                let errors: Vec<I> = errors
+                    .into_iter()
+                    .map(|entry| {
+                        locations.push(ErrorLocation::from(entry.location));
+                        extras.push(Extras {
+                            attachments: self::attachments(entry.fields),
+                            severity: entry.severity,
+                            ..Extras::default()
+                        });
+                        entry.error.into_inner()
+                    })
+                    .collect();
+
+                let omitted = Omitted {
+                    errors:   omitted_errors,
+                    warnings: omitted_warnings,
+                };
+
+                let errs = StashedErrors::from_error_locations(
+                    summary, errors, locations,
+                )
+                .with_extras(extras)
+                .with_omitted(omitted)
+                .with_aborted(aborted);
+                (ErrorData::Stashed(errs), fields)
+            }
+            OwnedError::Wrapped {
+                context,
+                fields,
+                location,
+                inner,
+            } => {
+                let err = WrappedError::from_parts(
+                    context.map(String::into_boxed_str),
+                    inner.into_inner(),
+                    location,
+                );
+                (ErrorData::Wrapped(err), fields)
+            }
+            OwnedError::AdHoc {
+                message,
+                fields,
+                location,
+                severity,
+            } => {
+                let message = message.into_boxed_str();
+                let err = AdHocError::from_parts(message, location)
+                    .with_severity(severity);
+                (ErrorData::AdHoc(err), fields)
+            }
+            OwnedError::Leaf(leaf) => (ErrorData::wrap(leaf), Vec::new()),
+        };
+
+        fields
+            .into_iter()
+            .fold(data, |data, (key, value)| data.attach_field(key, value))
+            .into()
+    }
+}
+
+/// Returns the fields of `attachments` as owned key/value pairs.
+fn fields(attachments: Option<&Attachments>) -> Vec<(String, String)> {
+    attachments
+        .into_iter()
+        .flat_map(Attachments::fields)
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Creates [`Attachments`] that contain the given fields.
+fn attachments(fields: Vec<(String, String)>) -> Attachments {
+    fields
+        .into_iter()
+        .fold(Attachments::new(), |attachments, (key, value)| {
+            attachments.with_field(key, value)
+        })
+}
+
+/// Returns the chain of errors returned by `core::error::Error::source`,
+/// excluding `err` itself.
+#[cfg(any(feature = "rust-v1.81", feature = "std"))]
+fn sources<I: InnerError>(err: &I) -> Vec<String> {
+    let mut sources = Vec::new();
+    let mut source = err
+        .as_error_source()
+        .and_then(|err| err.source());
+    while let Some(err) = source {
+        sources.push(err.to_string());
+        source = err.source();
+    }
+    sources
+}
+
+#[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+fn sources<I: InnerError>(_err: &I) -> Vec<String> {
+    Vec::new()
+}
+
+/// Used to skip serializing counts of omitted errors if there are none.
+#[cfg(feature = "serde")]
+fn is_zero(count: &usize) -> bool {
+    *count == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec, vec::Vec};
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use crate::{
+        strip_backtraces, Attachments, FailFast, OwnedError, OwnedLocation,
+        Retention, Severity,
+    };
+
+    fn nested_error() -> Error {
+        let mut inner = ErrorStash::new(|| "Inner summary");
+        inner.push("First error");
+        let warning: Error = crate::warn!("Second error");
+        inner.push_warning(warning);
+        let inner: Error = inner.into_result().unwrap_err();
+
+        let mut errs = ErrorStash::new(|| "Outer summary");
+        errs.push(Error::wrap_with(inner, "Context").attach_field("id", 1));
+        errs.push_with_attachments(
+            Error::wrap("Wrapped error"),
+            Attachments::new().with_field("retries", 3),
+        );
+        errs.into_result().unwrap_err()
+    }
+
+    #[test]
+    fn reconstructed_error_prints_like_original() {
+        let err = nested_error();
+        let copy: Error = Error::from(OwnedError::from(&err));
+
+        assert_eq!(format!("{copy}"), format!("{err}"));
+        let printed = strip_backtraces(&format!("{err:#}"));
+        assert_eq!(format!("{copy:#}"), printed);
+    }
+
+    #[test]
+    fn reconstructed_error_can_be_merged_into_stash() {
+        let err = nested_error();
+        let copy: Error = Error::from(OwnedError::from(&err));
+
+        let mut errs = ErrorStash::new(|| "Merged");
+        errs.push(copy);
+        errs.push("Local error");
+        let errs: Error = errs.into_result().unwrap_err();
+
+        let merged = &errs.children()[0];
+        assert_eq!(format!("{errs}"), "Merged (2 errors)");
+        assert_eq!(format!("{merged}"), format!("{err}"));
+        let printed = strip_backtraces(&format!("{err:#}"));
+        assert_eq!(strip_backtraces(&format!("{merged:#}")), printed);
+    }
+
+    #[test]
+    fn owned_location_prints_like_original() {
+        let err: Error = Error::from(OwnedError::AdHoc {
+            message:  "Remote error".into(),
+            fields:   vec![],
+            location: OwnedLocation {
+                file:   "src/remote.rs".into(),
+                line:   42,
+                column: 7,
+            },
+            severity: Severity::Error,
+        });
+
+        assert_eq!(format!("{err:#}"), "Remote error\nat src/remote.rs:42:7");
+    }
+
+    #[test]
+    fn reconstructed_error_keeps_original_locations() {
+        let owned = OwnedError::from(&nested_error());
+        let copy: Error = Error::from(owned.clone());
+
+        assert_eq!(OwnedError::from(&copy), owned);
+    }
+
+    #[test]
+    fn accessors_return_original_locations() {
+        let err = nested_error();
+        let copy: Error = Error::from(OwnedError::from(&err));
+
+        let original: Vec<_> = err
+            .walk()
+            .map(|n| n.location())
+            .collect();
+        let restored: Vec<_> = copy
+            .walk()
+            .map(|n| n.location())
+            .collect();
+        assert_eq!(restored, original);
+
+        match (err.as_ref(), copy.as_ref()) {
+            (ErrorData::Stashed(errs), ErrorData::Stashed(copies)) => {
+                assert_eq!(copies.locations(), errs.locations());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn attached_owned_location_is_not_printed() {
+        let location = OwnedLocation {
+            file:   "src/remote.rs".into(),
+            line:   42,
+            column: 7,
+        };
+
+        let err: Error = Error::from_message("Local error").attach(location);
+        let printed = format!("{err:#}");
+        assert!(!printed.contains("src/remote.rs"));
+    }
+
+    #[test]
+    fn reconstructed_error_keeps_omitted_errors_and_abort() {
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_retention(Retention::First(1))
+            .with_fail_fast(FailFast::MaxErrors(3));
+        errs.push("First error");
+        errs.push_warning("Warning");
+        errs.push("Second error");
+        errs.push("Third error");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let owned = OwnedError::from(&err);
+        match &owned {
+            OwnedError::Stashed {
+                omitted_errors,
+                omitted_warnings,
+                aborted,
+                ..
+            } => {
+                assert_eq!(*omitted_errors, 2);
+                assert_eq!(*omitted_warnings, 1);
+                assert!(*aborted);
+            }
+            _ => unreachable!(),
+        }
+
+        let copy: Error = Error::from(owned.clone());
+        assert_eq!(OwnedError::from(&copy), owned);
+        assert_eq!(format!("{copy}"), format!("{err}"));
+        let printed = strip_backtraces(&format!("{err:#}"));
+        assert_eq!(format!("{copy:#}"), printed);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&err).unwrap();
+            assert_eq!(json["omitted_errors"], 2);
+            assert_eq!(json["omitted_warnings"], 1);
+            assert_eq!(json["aborted"], true);
+
+            let deserialized: OwnedError =
+                serde_json::from_value(json).unwrap();
+            assert_eq!(deserialized, owned);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialized_error_prints_like_original() {
+        let err = nested_error();
+
+        let json = serde_json::to_string(&err).unwrap();
+        let owned: OwnedError = serde_json::from_str(&json).unwrap();
+        assert_eq!(owned, OwnedError::from(&err));
+
+        let copy: Error = Error::from(owned);
+        assert_eq!(format!("{copy}"), format!("{err}"));
+        let printed = strip_backtraces(&format!("{err:#}"));
+        assert_eq!(format!("{copy:#}"), printed);
+    }
+}
@@ -32,12 +32,20 @@
 //! [CUSTOM]: crate#example-custom-error-types
 
 pub use crate::{
-    err, try2, OrCreateStash, OrStash, OrWrap, OrWrapWith, StashErr,
-    TryCollectOrStash, TryMapOrStash,
+    err, try2, Attach, OrCreateStash, OrStash, OrWrap, OrWrapWith, StashErr,
+    TryCollectOrStash, TryMapOrStash, WithKind,
+};
+
+#[cfg(feature = "anyhow")]
+pub use crate::{
+    IntoAnyhowError, IntoAnyhowErrorLossy, IntoAnyhowResult,
+    IntoAnyhowResultLossy,
 };
 
 #[cfg(feature = "eyre")]
-pub use crate::{IntoEyreReport, IntoEyreResult};
+pub use crate::{
+    IntoEyreReport, IntoEyreReportLossy, IntoEyreResult, IntoEyreResultLossy,
+};
 
 /// Type alias for [`crate::StashedResult`]
 /// to use a boxed [_inner error type_ `I`](crate::Error#inner-error-type-i),
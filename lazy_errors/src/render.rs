@@ -0,0 +1,1102 @@
+use core::fmt::{self, Display, Write};
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "color")]
+use std::{ffi::OsString, io::IsTerminal};
+
+#[cfg(feature = "backtrace")]
+use crate::error::display_backtrace;
+
+#[cfg(feature = "tracing")]
+use crate::trace::display_span_trace;
+
+use crate::{
+    error::Note, Attachments, ErrorData, ErrorLocation, InnerError, NodeKind,
+    Severity, StashedErrors,
+};
+
+/// Prints the entire tree of an [`Error`] in a configurable layout.
+///
+/// When an [`Error`] is pretty-printed (`{:#}`), the output layout is fixed:
+/// Errors in a list are printed with `- ` bullets,
+/// nested lines are indented by two spaces,
+/// and source locations are printed in separate `at file:line:col` lines.
+/// `Renderer` prints the same tree, but allows you to change the layout.
+/// Without any options, `Renderer` produces the same output
+/// as pretty-printing the error:
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// use lazy_errors::{prelude::*, Renderer};
+///
+/// let mut inner = ErrorStash::new(|| "Failed to parse config");
+/// inner.push("Unexpected token");
+/// inner.push("Missing value");
+/// let inner: Error = inner.into_result().unwrap_err();
+///
+/// let mut errs = ErrorStash::new(|| "Failed to start");
+/// errs.push(inner);
+/// errs.push("Port already in use");
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let default = Renderer::new().render(&err).to_string();
+/// assert_eq!(default, format!("{err:#}"));
+///
+/// let compact = Renderer::new()
+///     .with_bullet("* ")
+///     .with_indent(4)
+///     .with_locations(false)
+///     .render(&err)
+///     .to_string();
+/// # let compact = replace_line_numbers(&compact);
+///
+/// assert_eq!(compact, indoc::indoc! {"
+///     Failed to start
+///     * Failed to parse config
+///         * Unexpected token
+///         * Missing value
+///     * Port already in use"});
+///
+/// let shallow = Renderer::new()
+///     .with_max_depth(1)
+///     .render(&err)
+///     .to_string();
+///
+/// let shallow = replace_line_numbers(&shallow);
+/// assert_eq!(shallow, indoc::indoc! {"
+///     Failed to start
+///     - Failed to parse config (2 errors)
+///       at src/render.rs:1234:56
+///     - Port already in use
+///       at src/render.rs:1234:56"});
+/// # }
+/// ```
+///
+/// Note that `Renderer` can only apply its layout to nested errors
+/// if the [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// allows `lazy_errors` to identify them
+/// (see [`InnerError::as_error_tree`]).
+/// Other nested errors will be pretty-printed (`{:#}`) as-is.
+///
+/// If the `color` feature is enabled, `Renderer` can highlight
+/// the output by ANSI escape codes (see `with_auto_color`).
+/// Note that errors are never highlighted
+/// when printed via [`Display`] directly.
+///
+/// [`Error`]: crate::Error
+#[derive(Clone, Debug)]
+pub struct Renderer {
+    layout: Layout,
+    bullet: Box<str>,
+    indent: usize,
+    locations: bool,
+    max_depth: Option<usize>,
+    inline_single_child: bool,
+    deduplicate: bool,
+
+    #[cfg(feature = "color")]
+    color: bool,
+}
+
+/// How a [`Renderer`] lays out the errors in a list.
+///
+/// [`Layout::Bullets`] is the layout of pretty-printed errors (`{:#}`).
+/// If errors are nested deeply, the tree layouts may be easier to read:
+/// They connect each error to its siblings and to its parent,
+/// thus showing which error belongs to which list at a glance.
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// use lazy_errors::{prelude::*, Layout, Renderer};
+///
+/// let mut child = ErrorStash::new(|| "Child failed");
+/// child.push("Bad config");
+/// child.push("Missing file");
+/// let child: Error = child.into_result().unwrap_err();
+///
+/// let mut parent = ErrorStash::new(|| "Parent failed");
+/// parent.push(Error::wrap_with(child, "In child #1"));
+/// parent.push("Timeout");
+/// let err: Error = parent.into_result().unwrap_err();
+///
+/// let tree = Renderer::new()
+///     .with_layout(Layout::Tree)
+///     .with_locations(false)
+///     .render(&err)
+///     .to_string();
+/// # let tree = replace_line_numbers(&tree);
+///
+/// assert_eq!(tree, indoc::indoc! {"
+///     Parent failed
+///     ├─ In child #1: Child failed
+///     │  ├─ Bad config
+///     │  └─ Missing file
+///     └─ Timeout"});
+///
+/// let ascii = Renderer::new()
+///     .with_layout(Layout::AsciiTree)
+///     .with_locations(false)
+///     .render(&err)
+///     .to_string();
+/// # let ascii = replace_line_numbers(&ascii);
+///
+/// assert_eq!(ascii, indoc::indoc! {"
+///     Parent failed
+///     |- In child #1: Child failed
+///     |  |- Bad config
+///     |  `- Missing file
+///     `- Timeout"});
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Prints a bullet in front of each error
+    /// and indents all other lines of the error.
+    /// This is the default. See [`Renderer::with_bullet`] and
+    /// [`Renderer::with_indent`].
+    Bullets,
+
+    /// Connects errors by box-drawing characters (`├─`, `│`, and `└─`).
+    Tree,
+
+    /// Like [`Layout::Tree`], but uses ASCII characters
+    /// (`|-`, `|`, and `` `- ``) instead of box-drawing characters.
+    AsciiTree,
+}
+
+/// An [`Error`](crate::Error) that is printed by a [`Renderer`]
+/// when formatted via [`Display`].
+///
+/// Created by [`Renderer::render`].
+#[derive(Debug)]
+pub struct Rendered<'a, I> {
+    renderer: &'a Renderer,
+    error:    &'a ErrorData<I>,
+}
+
+/// Entries of a list that are printed identically and have been added
+/// at the same source location, as grouped by
+/// [`Renderer::with_deduplication`].
+#[derive(Debug)]
+struct Group {
+    /// The index of the first entry of this group in the list.
+    index: usize,
+
+    /// The number of entries in this group.
+    count: usize,
+
+    /// The entry, printed by the [`Renderer`] without its footer.
+    printed: String,
+}
+
+/// The parts of the output that are highlighted if colors are enabled.
+#[derive(Clone, Copy, Debug)]
+enum Style {
+    Summary,
+    Context,
+    Message,
+    Warning,
+    Location,
+}
+
+/// Writes to `inner`, indenting each line except the first one.
+pub(crate) struct Indented<'a> {
+    pub(crate) inner:  &'a mut dyn Write,
+    pub(crate) indent: &'a str,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self {
+            layout: Layout::default(),
+            bullet: "- ".into(),
+            indent: 2,
+            locations: true,
+            max_depth: None,
+            inline_single_child: false,
+            deduplicate: false,
+            #[cfg(feature = "color")]
+            color: false,
+        }
+    }
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` would break MSRV
+impl Default for Layout {
+    fn default() -> Self {
+        Self::Bullets
+    }
+}
+
+impl Renderer {
+    /// Creates a `Renderer` that produces the same output as
+    /// pretty-printing (`{:#}`) an error.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how lists of errors are laid out. Defaults to [`Layout::Bullets`].
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the string that is printed in front of each error in a list.
+    /// Defaults to `- `. Ignored unless the layout is [`Layout::Bullets`].
+    pub fn with_bullet<B: Display>(mut self, bullet: B) -> Self {
+        self.bullet = bullet.to_string().into_boxed_str();
+        self
+    }
+
+    /// Sets the number of spaces by which
+    /// the lines of errors in a list are indented.
+    /// The first line is not indented but starts with the bullet instead.
+    /// Defaults to `2`. Ignored unless the layout is [`Layout::Bullets`].
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets whether source locations are printed.
+    /// Defaults to `true`.
+    pub fn with_locations(mut self, locations: bool) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Sets the maximum number of nested lists of errors to print.
+    /// Lists that are nested deeper will be printed in their short,
+    /// single-line form, for example `Summary (3 errors)`.
+    /// Thus, a maximum depth of zero prints the error on a single line.
+    /// There is no limit by default.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets whether lists that contain only a single error are printed
+    /// on the same line as their summary, e.g. `Summary: Error`,
+    /// instead of as a list with a single bullet point.
+    /// Defaults to `false`.
+    pub fn with_inline_single_child(mut self, inline: bool) -> Self {
+        self.inline_single_child = inline;
+        self
+    }
+
+    /// Sets whether entries of a list that are printed identically
+    /// and have been added at the same source location
+    /// are printed only once, followed by their count, e.g. `(×3)`.
+    /// Defaults to `false`.
+    ///
+    /// This keeps the output readable if the same error
+    /// occurred many times, for example when parsing thousands of lines
+    /// in a loop. Each group of entries is printed at the position
+    /// of its first entry. Fields, source locations, backtraces,
+    /// and span traces are printed for the first entry of each group only,
+    /// thus serving as a sample of the entries in the group.
+    /// The error itself is not affected,
+    /// i.e. [`children`](crate::ErrorData::children) still returns
+    /// all entries, including duplicates:
+    ///
+    /// ```
+    /// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// # {
+    /// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+    /// use lazy_errors::{prelude::*, Renderer};
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to parse input");
+    /// for token in ["1", "X", "3", "Y", "Z"] {
+    ///     token.parse::<u32>().or_stash(&mut errs);
+    /// }
+    /// errs.push("Unexpected end of input");
+    /// let err: Error = errs.into_result().unwrap_err();
+    ///
+    /// let rendered = Renderer::new()
+    ///     .with_deduplication(true)
+    ///     .render(&err)
+    ///     .to_string();
+    ///
+    /// let rendered = replace_line_numbers(&rendered);
+    /// assert_eq!(rendered, indoc::indoc! {"
+    ///     Failed to parse input
+    ///     - invalid digit found in string (×3)
+    ///       at src/render.rs:1234:56
+    ///     - Unexpected end of input
+    ///       at src/render.rs:1234:56"});
+    ///
+    /// assert_eq!(err.children().len(), 4);
+    /// # }
+    /// ```
+    pub fn with_deduplication(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    /// Sets whether summaries, messages, context, warnings,
+    /// and source locations are highlighted by ANSI escape codes.
+    /// Defaults to `false`.
+    ///
+    /// You probably want to call [`with_auto_color`](Self::with_auto_color)
+    /// instead, which respects the user's terminal and preferences.
+    #[cfg(feature = "color")]
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Enables colors (see [`with_color`](Self::with_color))
+    /// if `stream` is a terminal and the `NO_COLOR` environment variable
+    /// is not set (or empty); disables colors otherwise.
+    ///
+    /// Pass the stream that the rendered error will be printed to,
+    /// for example `std::io::stderr()` or `std::io::stdout()`:
+    ///
+    /// ```
+    /// use lazy_errors::Renderer;
+    ///
+    /// let for_stderr = Renderer::new().with_auto_color(&std::io::stderr());
+    /// let for_stdout = Renderer::new().with_auto_color(&std::io::stdout());
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn with_auto_color<S: IsTerminal>(self, stream: &S) -> Self {
+        let no_color = std::env::var_os("NO_COLOR");
+        let is_terminal = stream.is_terminal();
+        self.with_color(is_color_supported(no_color, is_terminal))
+    }
+
+    /// Returns a value that prints `error` in the layout of this `Renderer`
+    /// when formatted via [`Display`].
+    pub fn render<'a, I>(&'a self, error: &'a ErrorData<I>) -> Rendered<'a, I> {
+        Rendered {
+            renderer: self,
+            error,
+        }
+    }
+
+    fn node<I>(
+        &self,
+        f: &mut dyn Write,
+        node: NodeKind<'_, I>,
+        depth: usize,
+    ) -> fmt::Result
+    where
+        I: Display + InnerError,
+    {
+        match node {
+            NodeKind::Stashed(errs) => self.list(f, errs, depth),
+            NodeKind::Wrapped(err) => {
+                if let Some(context) = err.context() {
+                    self.paint(f, Style::Context, &context)?;
+                    write!(f, ": ")?;
+                }
+                self.child(f, err.inner(), depth)?;
+                self.footer(f, "", err.attachments(), err.location())?;
+                #[cfg(feature = "backtrace")]
+                display_backtrace(f, "", err.backtrace())?;
+                #[cfg(feature = "tracing")]
+                display_span_trace(f, "", err.span_trace())?;
+                Ok(())
+            }
+            NodeKind::AdHoc(err) => {
+                self.paint(f, Style::Message, &err.message())?;
+                self.footer(f, "", err.attachments(), err.location())?;
+                #[cfg(feature = "backtrace")]
+                display_backtrace(f, "", err.backtrace())?;
+                Ok(())
+            }
+            NodeKind::Leaf(err) => self.leaf(f, err),
+        }
+    }
+
+    fn child<I>(&self, f: &mut dyn Write, err: &I, depth: usize) -> fmt::Result
+    where
+        I: Display + InnerError,
+    {
+        match err.as_error_tree() {
+            Some(node) => self.node(f, node, depth),
+            None => self.leaf(f, err),
+        }
+    }
+
+    fn leaf<I: Display>(&self, f: &mut dyn Write, err: &I) -> fmt::Result {
+        self.paint(f, Style::Message, &format_args!("{err:#}"))
+    }
+
+    fn list<I>(
+        &self,
+        f: &mut dyn Write,
+        errs: &StashedErrors<I>,
+        depth: usize,
+    ) -> fmt::Result
+    where
+        I: Display + InnerError,
+    {
+        let is_too_deep =
+            matches!(self.max_depth, Some(max_depth) if depth >= max_depth);
+
+        match errs.errors() {
+            [] => write!(f, "{errs:#}"),
+            _ if is_too_deep => self.paint(f, Style::Summary, errs),
+            [err] if self.inline_single_child && !errs.has_notes() => {
+                self.paint(f, Style::Summary, &errs.summary())?;
+                write!(f, ": ")?;
+                self.severity(f, errs.severity(0))?;
+                self.child(f, err, depth)?;
+                errs.attachments()
+                    .display_fields(f, "")?;
+                self.entry_footer(f, errs, 0, "")
+            }
+            _ if self.deduplicate => self.deduplicated_list(f, errs, depth),
+            _ => {
+                self.paint(f, Style::Summary, &errs.summary())?;
+                errs.attachments()
+                    .display_fields(f, "")?;
+
+                let spaces = " ".repeat(self.indent);
+                let last = errs.errors().len() - 1;
+                let has_notes = errs.has_notes();
+                for (i, err) in errs.errors().iter().enumerate() {
+                    let is_last = i == last && !has_notes;
+                    let (bullet, indent) = self.prefixes(&spaces, is_last);
+
+                    writeln!(f)?;
+                    write!(f, "{bullet}")?;
+                    self.severity(f, errs.severity(i))?;
+
+                    let mut indented = Indented { inner: f, indent };
+                    self.child(&mut indented, err, depth + 1)?;
+                    self.entry_footer(f, errs, i, indent)?;
+                }
+                self.notes(f, errs, &spaces)
+            }
+        }
+    }
+
+    /// Prints a list like [`list`](Self::list) does, but prints entries
+    /// only once if they are identical (see [`Renderer::with_deduplication`]).
+    fn deduplicated_list<I>(
+        &self,
+        f: &mut dyn Write,
+        errs: &StashedErrors<I>,
+        depth: usize,
+    ) -> fmt::Result
+    where
+        I: Display + InnerError,
+    {
+        self.paint(f, Style::Summary, &errs.summary())?;
+        errs.attachments()
+            .display_fields(f, "")?;
+
+        let groups = self.groups(errs, depth)?;
+
+        let spaces = " ".repeat(self.indent);
+        let last = groups.len() - 1;
+        let has_notes = errs.has_notes();
+        for (i, group) in groups.iter().enumerate() {
+            let is_last = i == last && !has_notes;
+            let (bullet, indent) = self.prefixes(&spaces, is_last);
+
+            writeln!(f)?;
+            write!(f, "{bullet}")?;
+            self.severity(f, errs.severity(group.index))?;
+
+            let (first_line, other_lines) = match group.printed.find('\n') {
+                Some(i) => group.printed.split_at(i),
+                None => (group.printed.as_str(), ""),
+            };
+
+            let mut indented = Indented { inner: f, indent };
+            indented.write_str(first_line)?;
+            if group.count > 1 {
+                write!(indented, " (×{})", group.count)?;
+            }
+            indented.write_str(other_lines)?;
+
+            self.entry_footer(f, errs, group.index, indent)?;
+        }
+        self.notes(f, errs, &spaces)
+    }
+
+    /// Prints notes such as `and N more errors` or `aborted early`
+    /// as last entries of the list if errors have been dropped
+    /// from the stash because of its [`Retention`](crate::Retention)
+    /// or if its [`FailFast`](crate::FailFast) limit has been reached.
+    fn notes<I>(
+        &self,
+        f: &mut dyn Write,
+        errs: &StashedErrors<I>,
+        spaces: &str,
+    ) -> fmt::Result {
+        let notes: Vec<Note> = errs.notes().collect();
+        let last = notes.len().saturating_sub(1);
+        for (i, note) in notes.iter().enumerate() {
+            let (bullet, _) = self.prefixes(spaces, i == last);
+            writeln!(f)?;
+            write!(f, "{bullet}{note}")?;
+        }
+        Ok(())
+    }
+
+    /// Groups the entries of `errs` that are printed identically,
+    /// have the same severity, and have been added at the same location,
+    /// in the order of their first occurrence.
+    fn groups<I>(
+        &self,
+        errs: &StashedErrors<I>,
+        depth: usize,
+    ) -> Result<Vec<Group>, fmt::Error>
+    where
+        I: Display + InnerError,
+    {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut lookup: BTreeMap<_, usize> = BTreeMap::new();
+
+        for (index, err) in errs.errors().iter().enumerate() {
+            let mut printed = String::new();
+            self.child(&mut printed, err, depth + 1)?;
+
+            let location = errs
+                .locations()
+                .get(index)
+                .map(|l| (l.file(), l.line(), l.column()));
+
+            let key = (errs.severity(index), printed, location);
+            match lookup.get(&key) {
+                Some(&i) => groups[i].count += 1,
+                None => {
+                    groups.push(Group {
+                        index,
+                        count: 1,
+                        printed: key.1.clone(),
+                    });
+                    lookup.insert(key, groups.len() - 1);
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Returns the string to print in front of the first line of an error
+    /// in a list and the string to print in front of all other lines.
+    fn prefixes<'a>(
+        &'a self,
+        spaces: &'a str,
+        is_last: bool,
+    ) -> (&'a str, &'a str) {
+        match (self.layout, is_last) {
+            (Layout::Bullets, _) => (&self.bullet, spaces),
+            (Layout::Tree, false) => ("├─ ", "│  "),
+            (Layout::Tree, true) => ("└─ ", "   "),
+            (Layout::AsciiTree, false) => ("|- ", "|  "),
+            (Layout::AsciiTree, true) => ("`- ", "   "),
+        }
+    }
+
+    /// Prints the fields, location, backtrace, and span trace
+    /// that were recorded for the error at `index` by the list.
+    fn entry_footer<I>(
+        &self,
+        f: &mut dyn Write,
+        errs: &StashedErrors<I>,
+        index: usize,
+        indent: &str,
+    ) -> fmt::Result {
+        if let Some(attachments) = errs.entry_attachments(index) {
+            attachments.display_fields(f, indent)?;
+        }
+
+        if let Some(location) = errs.locations().get(index) {
+            self.location(f, indent, location)?;
+        }
+
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = errs.entry_backtrace(index) {
+            display_backtrace(f, indent, backtrace)?;
+        }
+
+        #[cfg(feature = "tracing")]
+        if let Some(span_trace) = errs.entry_span_trace(index) {
+            display_span_trace(f, indent, span_trace)?;
+        }
+
+        Ok(())
+    }
+
+    fn footer(
+        &self,
+        f: &mut dyn Write,
+        indent: &str,
+        attachments: &Attachments,
+        location: &ErrorLocation,
+    ) -> fmt::Result {
+        attachments.display_fields(f, indent)?;
+        self.location(f, indent, location)
+    }
+
+    fn location(
+        &self,
+        f: &mut dyn Write,
+        indent: &str,
+        location: &ErrorLocation,
+    ) -> fmt::Result {
+        if !self.locations {
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        write!(f, "{indent}")?;
+        self.paint(f, Style::Location, &format_args!("at {location}"))
+    }
+
+    /// Prints the `warning: ` prefix if `severity` is a warning.
+    fn severity(&self, f: &mut dyn Write, severity: Severity) -> fmt::Result {
+        match severity {
+            Severity::Warning => {
+                self.paint(f, Style::Warning, &"warning:")?;
+                write!(f, " ")
+            }
+            Severity::Error => Ok(()),
+        }
+    }
+
+    /// Prints `text`, highlighted by ANSI escape codes if colors are enabled.
+    fn paint(
+        &self,
+        f: &mut dyn Write,
+        style: Style,
+        text: &dyn Display,
+    ) -> fmt::Result {
+        #[cfg(feature = "color")]
+        if self.color {
+            let code = style.ansi_code();
+            return write!(f, "\x1b[{code}m{text}\x1b[0m");
+        }
+
+        #[cfg(not(feature = "color"))]
+        let _ = style;
+
+        write!(f, "{text}")
+    }
+}
+
+impl Style {
+    #[cfg(feature = "color")]
+    fn ansi_code(self) -> &'static str {
+        match self {
+            Self::Summary => "1",
+            Self::Context => "36",
+            Self::Message => "31",
+            Self::Warning => "33",
+            Self::Location => "2",
+        }
+    }
+}
+
+/// Returns whether colors should be enabled automatically,
+/// based on the value of the `NO_COLOR` environment variable and
+/// on whether the output stream is a terminal.
+#[cfg(feature = "color")]
+fn is_color_supported(no_color: Option<OsString>, is_terminal: bool) -> bool {
+    let is_disabled = no_color.is_some_and(|value| !value.is_empty());
+    is_terminal && !is_disabled
+}
+
+impl<I> Display for Rendered<'_, I>
+where
+    I: Display + InnerError,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node = NodeKind::from(self.error);
+        self.renderer.node(f, node, 0)
+    }
+}
+
+impl Write for Indented<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.inner.write_str(first)?;
+        }
+        for line in lines {
+            self.inner.write_char('\n')?;
+            self.inner.write_str(self.indent)?;
+            self.inner.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use crate::{strip_backtraces, Renderer};
+
+    fn nested_error() -> Error {
+        let mut inner = ErrorStash::new(|| "Inner summary");
+        inner.push("First error");
+        inner.push_warning(Error::from_message("Second error"));
+        let inner: Error = inner.into_result().unwrap_err();
+
+        let mut single = ErrorStash::new(|| "Single summary");
+        single.push("Only error");
+        let single: Error = single.into_result().unwrap_err();
+
+        let mut errs = ErrorStash::new(|| "Outer summary");
+        errs.push(Error::wrap_with(inner, "Context").attach_field("id", 1));
+        errs.push(single);
+        errs.push(Error::wrap("Wrapped error"));
+        errs.into_result().unwrap_err()
+    }
+
+    #[test]
+    fn default_renderer_prints_like_pretty_display() {
+        let err = nested_error();
+        let rendered = Renderer::new().render(&err).to_string();
+        assert_eq!(rendered, format!("{err:#}"));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn default_renderer_prints_span_traces_like_pretty_display() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let subscriber = tracing_subscriber::Registry::default()
+            .with(tracing_error::ErrorLayer::default());
+        let _guard = tracing::subscriber::set_default(subscriber);
+        let span = tracing::info_span!("render_test");
+        let _entered = span.enter();
+
+        let err = nested_error();
+        let printed = format!("{err:#}");
+        assert!(printed.contains("span trace:"));
+
+        let rendered = Renderer::default()
+            .render(&err)
+            .to_string();
+        assert_eq!(rendered, printed);
+    }
+
+    #[test]
+    fn max_depth_zero_prints_single_line() {
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_max_depth(0)
+            .render(&err)
+            .to_string();
+        assert_eq!(rendered, "Outer summary (3 errors)");
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn inline_single_child() {
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_inline_single_child(true)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(rendered, indoc::indoc! {"
+            Outer summary
+            - Context: Inner summary
+              - First error
+              - warning: Second error
+              id: 1
+            - Single summary: Only error
+            - Wrapped error"});
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn custom_bullet_and_indent() {
+        use crate::doctest_line_num_helper as replace_line_numbers;
+
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_bullet("+ ")
+            .with_indent(3)
+            .render(&err)
+            .to_string();
+        let rendered = replace_line_numbers(&rendered);
+
+        assert_eq!(rendered, indoc::indoc! {"
+            Outer summary
+            + Context: Inner summary
+               + First error
+                  at src/render.rs:1234:56
+               + warning: Second error
+                  at src/render.rs:1234:56
+                  at src/render.rs:1234:56
+               id: 1
+               at src/render.rs:1234:56
+               at src/render.rs:1234:56
+            + Single summary
+               + Only error
+                  at src/render.rs:1234:56
+               at src/render.rs:1234:56
+            + Wrapped error
+               at src/render.rs:1234:56
+               at src/render.rs:1234:56"});
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn tree_layout() {
+        use crate::{doctest_line_num_helper as replace_line_numbers, Layout};
+
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_layout(Layout::Tree)
+            .render(&err)
+            .to_string();
+        let rendered = replace_line_numbers(&rendered);
+
+        assert_eq!(rendered, indoc::indoc! {"
+            Outer summary
+            ├─ Context: Inner summary
+            │  ├─ First error
+            │  │  at src/render.rs:1234:56
+            │  └─ warning: Second error
+            │     at src/render.rs:1234:56
+            │     at src/render.rs:1234:56
+            │  id: 1
+            │  at src/render.rs:1234:56
+            │  at src/render.rs:1234:56
+            ├─ Single summary
+            │  └─ Only error
+            │     at src/render.rs:1234:56
+            │  at src/render.rs:1234:56
+            └─ Wrapped error
+               at src/render.rs:1234:56
+               at src/render.rs:1234:56"});
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn ascii_tree_layout_ignores_bullet_and_indent() {
+        use crate::Layout;
+
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_layout(Layout::AsciiTree)
+            .with_bullet("* ")
+            .with_indent(4)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(rendered, indoc::indoc! {"
+            Outer summary
+            |- Context: Inner summary
+            |  |- First error
+            |  `- warning: Second error
+            |  id: 1
+            |- Single summary
+            |  `- Only error
+            `- Wrapped error"});
+    }
+
+    #[test]
+    fn deduplication_groups_identical_entries() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        for _ in 0..3 {
+            errs.push("Same error");
+        }
+        errs.push("Same error");
+        for _ in 0..2 {
+            errs.push_warning("Same error");
+        }
+        let err: Error = errs.into_result().unwrap_err();
+
+        let rendered = Renderer::new()
+            .with_deduplication(true)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(
+            rendered,
+            "Summary\n\
+             - Same error (×3)\n\
+             - Same error\n\
+             - warning: Same error (×2)"
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn deduplication_prints_count_on_first_line() {
+        use crate::Layout;
+
+        fn child() -> Error {
+            let mut errs = ErrorStash::new(|| "Child failed");
+            errs.push("Bad config");
+            errs.push("Missing file");
+            errs.into_result().unwrap_err()
+        }
+
+        let mut errs = ErrorStash::new(|| "Parent failed");
+        for _ in 0..2 {
+            errs.push(child());
+        }
+        let err: Error = errs.into_result().unwrap_err();
+
+        let rendered = Renderer::new()
+            .with_layout(Layout::Tree)
+            .with_deduplication(true)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(rendered, indoc::indoc! {"
+            Parent failed
+            └─ Child failed (×2)
+               ├─ Bad config
+               └─ Missing file"});
+    }
+
+    #[test]
+    fn omitted_errors_are_last_entry() {
+        use crate::{Layout, Retention};
+
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::First(2));
+        for _ in 0..5 {
+            errs.push("Same error");
+        }
+        let err: Error = errs.into_result().unwrap_err();
+
+        let rendered = Renderer::new()
+            .with_layout(Layout::Tree)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(
+            rendered,
+            "Summary\n\
+             ├─ Same error\n\
+             ├─ Same error\n\
+             └─ and 3 more errors"
+        );
+
+        let rendered = Renderer::new()
+            .with_deduplication(true)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(
+            rendered,
+            "Summary\n\
+             - Same error (×2)\n\
+             - and 3 more errors"
+        );
+    }
+
+    #[test]
+    fn aborted_note_is_last_entry() {
+        use crate::{FailFast, Layout, Retention};
+
+        let mut errs = ErrorStash::new(|| "Summary")
+            .with_retention(Retention::First(1))
+            .with_fail_fast(FailFast::MaxErrors(2));
+        errs.push("First error");
+        errs.push("Second error");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let rendered = Renderer::new()
+            .with_layout(Layout::Tree)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(
+            rendered,
+            "Summary\n\
+             ├─ First error\n\
+             ├─ and 1 more error\n\
+             └─ aborted early"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn color_highlights_output() {
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_color(true)
+            .with_locations(false)
+            .render(&err)
+            .to_string();
+        let rendered = strip_backtraces(&rendered);
+
+        assert_eq!(rendered, indoc::indoc! {"
+            \x1b[1mOuter summary\x1b[0m
+            - \x1b[36mContext\x1b[0m: \x1b[1mInner summary\x1b[0m
+              - \x1b[31mFirst error\x1b[0m
+              - \x1b[33mwarning:\x1b[0m \x1b[31mSecond error\x1b[0m
+              id: 1
+            - \x1b[1mSingle summary\x1b[0m
+              - \x1b[31mOnly error\x1b[0m
+            - \x1b[31mWrapped error\x1b[0m"});
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn color_disabled_prints_like_pretty_display() {
+        let err = nested_error();
+        let rendered = Renderer::new()
+            .with_color(false)
+            .render(&err)
+            .to_string();
+        assert_eq!(rendered, format!("{err:#}"));
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn auto_color_respects_no_color_and_terminal() {
+        use super::is_color_supported;
+
+        assert!(is_color_supported(None, true));
+        assert!(is_color_supported(Some("".into()), true));
+        assert!(!is_color_supported(Some("1".into()), true));
+        assert!(!is_color_supported(None, false));
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn auto_color_checks_given_stream() {
+        let err = nested_error();
+        let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let file = std::fs::File::open(manifest).unwrap();
+        let renderer = Renderer::new().with_auto_color(&file);
+        let rendered = renderer.render(&err).to_string();
+        assert_eq!(rendered, format!("{err:#}"));
+    }
+}
@@ -0,0 +1,229 @@
+use core::fmt::{self, Debug, Display};
+
+use std::process::{ExitCode, Termination};
+
+use crate::{Error, InnerError, Renderer};
+
+/// Alias of the `Result` to return from `main`,
+/// which prints errors in a human-readable way.
+///
+/// When `main` returns `Err`, the standard library prints the
+/// [`Debug`] representation of the error and exits with code `1`.
+/// Since the [`Debug`] representation of [`Report`] is the
+/// pretty-printed error tree, returning [`MainResult`] from `main`
+/// gives you readable output without having to print errors manually.
+/// Any [`Error`] (or [`StashWithErrors`](crate::StashWithErrors))
+/// can be converted into a [`Report`] by the `?` operator.
+///
+/// ```
+/// use lazy_errors::{prelude::*, MainResult, Result};
+///
+/// fn main() -> MainResult {
+///     run()?;
+///     Ok(())
+/// }
+///
+/// fn run() -> Result<()> {
+///     let mut errs = ErrorStash::new(|| "Failed to run");
+///     // ...
+///     errs.into()
+/// }
+/// ```
+///
+/// If `main` fails, this will print, for example:
+///
+/// ```text
+/// Error: Failed to run
+/// - Input is invalid
+///   at src/main.rs:12:34
+/// ```
+///
+/// If you need other exit codes than `1`, please take a look at
+/// [`Report`].
+pub type MainResult<T = (), I = crate::prelude::Stashable> =
+    core::result::Result<T, Report<I>>;
+
+/// Wraps an [`Error`] to print it in a human-readable way
+/// when returning it from `main`.
+///
+/// The [`Debug`] representation of `Report` is the error tree
+/// as printed by a [`Renderer`], which (by default) is the same as
+/// pretty-printing (`{:#}`) the error.
+/// If the `color` feature is enabled, the default [`Renderer`] highlights
+/// the output as described in `Renderer::with_auto_color`
+/// if `stderr` is a terminal.
+/// The standard library prints that representation
+/// if `main` returns a [`MainResult`] that is `Err`.
+///
+/// `Report` also implements [`Termination`].
+/// [`Termination::report`] prints the error to `stderr`
+/// and returns the exit code of the `Report`,
+/// which is `1` by default but can be configured
+/// (see [`with_exit_code`](Self::with_exit_code)):
+///
+/// ```
+/// use std::process::{ExitCode, Termination};
+///
+/// use lazy_errors::{prelude::*, Report, Result};
+///
+/// fn main() -> ExitCode {
+///     match run() {
+///         Ok(()) => ExitCode::SUCCESS,
+///         Err(err) => Report::from(err)
+///             .with_exit_code(2)
+///             .report(),
+///     }
+/// }
+///
+/// fn run() -> Result<()> {
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Termination`]: std::process::Termination
+/// [`Termination::report`]: std::process::Termination::report
+pub struct Report<I = crate::prelude::Stashable> {
+    error:     Error<I>,
+    renderer:  Renderer,
+    exit_code: u8,
+}
+
+impl<I> Report<I> {
+    /// Creates a `Report` that prints `error` by the default [`Renderer`]
+    /// and exits with code `1` when used as [`Termination`].
+    pub fn new(error: Error<I>) -> Self {
+        Self {
+            error,
+            renderer: default_renderer(),
+            exit_code: 1,
+        }
+    }
+
+    /// Sets the [`Renderer`] that prints the error.
+    pub fn with_renderer(mut self, renderer: Renderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    /// Sets the exit code that is returned from [`Termination::report`].
+    /// Defaults to `1`.
+    ///
+    /// Note that this does not affect the exit code of `main`
+    /// if `main` returns a [`MainResult`]:
+    /// In that case, the standard library always exits with code `1`.
+    pub fn with_exit_code(mut self, exit_code: u8) -> Self {
+        self.exit_code = exit_code;
+        self
+    }
+
+    /// Returns the exit code that is returned from [`Termination::report`].
+    pub fn exit_code(&self) -> u8 {
+        self.exit_code
+    }
+
+    /// Returns the wrapped error.
+    pub fn error(&self) -> &Error<I> {
+        &self.error
+    }
+
+    /// Returns the wrapped error, discarding the `Report`.
+    pub fn into_error(self) -> Error<I> {
+        self.error
+    }
+}
+
+impl<I, E> From<E> for Report<I>
+where
+    E: Into<Error<I>>,
+{
+    fn from(error: E) -> Self {
+        Self::new(error.into())
+    }
+}
+
+impl<I> Debug for Report<I>
+where
+    I: Display + InnerError,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.renderer.render(&self.error), f)
+    }
+}
+
+impl<I> Display for Report<I>
+where
+    I: Display + InnerError,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.renderer.render(&self.error), f)
+    }
+}
+
+impl<I> Termination for Report<I>
+where
+    I: Display + InnerError,
+{
+    fn report(self) -> ExitCode {
+        eprintln!("{self}");
+        ExitCode::from(self.exit_code)
+    }
+}
+
+#[cfg(feature = "color")]
+pub(crate) fn default_renderer() -> Renderer {
+    Renderer::new().with_auto_color(&std::io::stderr())
+}
+
+#[cfg(not(feature = "color"))]
+pub(crate) fn default_renderer() -> Renderer {
+    Renderer::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        format,
+        process::{ExitCode, Termination},
+    };
+
+    use crate::{prelude::*, MainResult, Renderer, Report};
+
+    fn failing_main() -> MainResult {
+        let mut errs = ErrorStash::new(|| "Failed to run");
+        errs.push("First error");
+        errs.push("Second error");
+        errs.into_result()?;
+        Ok(())
+    }
+
+    #[test]
+    fn debug_prints_error_tree() {
+        let report = failing_main().unwrap_err();
+        assert_eq!(format!("{report:?}"), format!("{:#}", report.error()));
+    }
+
+    #[test]
+    fn debug_uses_renderer() {
+        let report = failing_main()
+            .unwrap_err()
+            .with_renderer(Renderer::new().with_max_depth(0));
+        assert_eq!(format!("{report:?}"), "Failed to run (2 errors)");
+    }
+
+    #[test]
+    fn from_stash_with_errors() {
+        let errs = StashWithErrors::from("Failed", "Error");
+        let report: Report = Report::from(errs);
+        assert_eq!(format!("{}", report.error()), "Failed: Error");
+    }
+
+    #[test]
+    fn report_returns_exit_code() {
+        let report = failing_main().unwrap_err();
+        assert_eq!(report.exit_code(), 1);
+
+        let report = report.with_exit_code(42);
+        assert_eq!(report.exit_code(), 42);
+        assert_eq!(report.report(), ExitCode::from(42));
+    }
+}
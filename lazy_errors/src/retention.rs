@@ -0,0 +1,382 @@
+use core::fmt;
+
+use crate::Severity;
+
+/// Limits the number of errors that an [`ErrorStash`] retains
+/// and selects which errors to retain if more errors are added.
+///
+/// By default, stashes retain all errors that are added to them.
+/// If your program may add a huge number of errors, for example
+/// when importing a large file that turns out to be garbage,
+/// you can limit the memory that the stash will use
+/// by calling `with_retention` on [`ErrorStash`] or [`StashWithErrors`].
+/// The stash will then count the errors that it did not retain,
+/// and print that count as `and N more errors`
+/// (or `and N more errors, M more warnings` if warnings were dropped too):
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// use lazy_errors::prelude::*;
+///
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// use lazy_errors::surrogate_error_trait::prelude::*;
+///
+/// use lazy_errors::Retention;
+///
+/// let mut errs = ErrorStash::new(|| "Failed to import")
+///     .with_retention(Retention::First(2));
+///
+/// for token in ["1", "A", "B", "4", "C", "D"] {
+///     token.parse::<u32>().or_stash(&mut errs);
+/// }
+///
+/// let err: Error = errs.into_result().unwrap_err();
+/// assert_eq!(err.children().len(), 2);
+///
+/// let printed = format!("{err}");
+/// assert_eq!(printed, "Failed to import (2 errors, and 2 more errors)");
+///
+/// let printed = format!("{err:#}");
+/// let printed = replace_line_numbers(&printed);
+/// assert_eq!(printed, indoc::indoc! {"
+///     Failed to import
+///     - invalid digit found in string
+///       at src/retention.rs:1234:56
+///     - invalid digit found in string
+///       at src/retention.rs:1234:56
+///     - and 2 more errors"});
+/// ```
+///
+/// A stash always retains at least one error,
+/// so a limit of zero is treated as a limit of one.
+/// Hooks such as `with_tracing` or `with_logging`
+/// are notified about all errors, including those that are not retained.
+/// Errors that have not been retained are still taken into account
+/// by methods such as [`StashWithErrors::has_errors`].
+///
+/// [`ErrorStash`]: crate::ErrorStash
+/// [`StashWithErrors`]: crate::StashWithErrors
+/// [`StashWithErrors::has_errors`]: crate::StashWithErrors::has_errors
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Retention {
+    /// Retains the first `n` errors and drops all errors added afterwards.
+    First(usize),
+
+    /// Retains the last `n` errors,
+    /// dropping the oldest error whenever another error is added.
+    Last(usize),
+
+    /// Retains a sample of `n` errors that is drawn uniformly
+    /// from all errors added to the stash (reservoir sampling).
+    ///
+    /// The sample is drawn by a pseudo-random number generator
+    /// that is seeded by a constant, i.e. the same sequence of errors
+    /// will always result in the same sample.
+    /// Since an error that is sampled replaces a random error
+    /// that has been retained so far, the retained errors
+    /// are _not_ kept in the order in which they were added.
+    Sample(usize),
+}
+
+/// Counts the errors that have been dropped from a stash
+/// because of its [`Retention`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Omitted {
+    pub(crate) errors:   usize,
+    pub(crate) warnings: usize,
+}
+
+/// A pseudo-random number generator (xorshift64*)
+/// that selects the errors to retain for [`Retention::Sample`].
+#[derive(Clone, Debug)]
+pub(crate) struct Sampler(u64);
+
+impl Retention {
+    /// Returns the maximum number of errors to retain, which is at least one.
+    pub(crate) fn limit(self) -> usize {
+        let n = match self {
+            Self::First(n) | Self::Last(n) | Self::Sample(n) => n,
+        };
+        Ord::max(n, 1)
+    }
+}
+
+impl Omitted {
+    /// Returns the number of errors and warnings that have been dropped.
+    pub(crate) fn count(&self) -> usize {
+        self.errors + self.warnings
+    }
+
+    pub(crate) fn add(&mut self, severity: Severity) {
+        match severity {
+            Severity::Warning => self.warnings += 1,
+            Severity::Error => self.errors += 1,
+        }
+    }
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Self(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+impl Sampler {
+    /// Returns a pseudo-random number in `0..bound`.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        let random = self
+            .0
+            .wrapping_mul(0x2545_F491_4F6C_DD1D);
+        (random % bound as u64) as usize
+    }
+}
+
+/// Prints `and N more errors, M more warnings`, where `N` and `M` are
+/// the numbers of errors and warnings that have been dropped from a list
+/// because of its [`Retention`]. Counts of zero are left out.
+pub(crate) fn display_omitted<W: fmt::Write + ?Sized>(
+    f: &mut W,
+    omitted: Omitted,
+) -> fmt::Result {
+    write!(f, "and ")?;
+    match (omitted.errors, omitted.warnings) {
+        (errors, 0) => display_more(f, errors, "error"),
+        (0, warnings) => display_more(f, warnings, "warning"),
+        (errors, warnings) => {
+            display_more(f, errors, "error")?;
+            write!(f, ", ")?;
+            display_more(f, warnings, "warning")
+        }
+    }
+}
+
+/// Prints `N more` followed by `noun` in singular or plural form.
+fn display_more<W: fmt::Write + ?Sized>(
+    f: &mut W,
+    count: usize,
+    noun: &str,
+) -> fmt::Result {
+    match count {
+        1 => write!(f, "1 more {noun}"),
+        n => write!(f, "{n} more {noun}s"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use crate::{Retention, Severity};
+
+    fn stash(retention: Retention, count: usize) -> Error {
+        let mut errs = ErrorStash::new(|| "Summary").with_retention(retention);
+        for i in 0..count {
+            errs.push(format!("Error #{i}"));
+        }
+        errs.into_result().unwrap_err()
+    }
+
+    fn printed_children(err: &Error) -> Vec<String> {
+        err.children()
+            .iter()
+            .map(|e| format!("{e}"))
+            .collect()
+    }
+
+    #[test]
+    fn first_retains_first_errors() {
+        let err = stash(Retention::First(2), 5);
+        assert_eq!(printed_children(&err), ["Error #0", "Error #1"]);
+        assert_eq!(err.to_string(), "Summary (2 errors, and 3 more errors)");
+    }
+
+    #[test]
+    fn omitted_errors_and_warnings_are_printed_separately() {
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::First(1));
+        errs.push("Error #0");
+        errs.push("Error #1");
+        errs.push_warning("Warning #0");
+        errs.push("Error #2");
+        let err: Error = errs.into_result().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Summary: Error #0 (and 2 more errors, 1 more warning)"
+        );
+
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::First(1));
+        errs.push("Error #0");
+        errs.push_warning("Warning #0");
+        errs.push_warning("Warning #1");
+        let err: Error = errs.into_result().unwrap_err();
+
+        assert_eq!(err.to_string(), "Summary: Error #0 (and 2 more warnings)");
+    }
+
+    #[test]
+    fn last_retains_last_errors() {
+        let err = stash(Retention::Last(2), 5);
+        assert_eq!(printed_children(&err), ["Error #3", "Error #4"]);
+    }
+
+    #[test]
+    fn sample_retains_distinct_errors() {
+        let err = stash(Retention::Sample(10), 1000);
+        let children = printed_children(&err);
+        assert_eq!(children.len(), 10);
+        assert_eq!(err.to_string(), "Summary (10 errors, and 990 more errors)");
+
+        let indices: Vec<usize> = children
+            .iter()
+            .map(|e| e["Error #".len()..].parse().unwrap())
+            .collect();
+
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 10);
+        assert!(indices.iter().all(|&i| i < 1000));
+
+        // Not just the first or the last errors
+        assert!(indices.iter().any(|&i| i >= 10));
+        assert!(indices.iter().any(|&i| i < 990));
+    }
+
+    #[test]
+    fn last_keeps_data_of_retained_errors() {
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::Last(3));
+
+        for i in 0..10 {
+            match i % 2 {
+                0 => errs.push(format!("Error #{i}")),
+                _ => errs.push_warning(format!("Error #{i}")),
+            };
+        }
+
+        let errs = match &errs {
+            ErrorStash::WithErrors(errs) => errs,
+            ErrorStash::Empty(..) => unreachable!(),
+        };
+
+        let printed: Vec<String> = errs
+            .errors()
+            .iter()
+            .map(|e| format!("{e}"))
+            .collect();
+
+        assert_eq!(printed, ["Error #7", "Error #8", "Error #9"]);
+        assert_eq!(errs.locations().len(), 3);
+        assert_eq!(errs.severity(0), Severity::Warning);
+        assert_eq!(errs.severity(1), Severity::Error);
+        assert_eq!(errs.severity(2), Severity::Warning);
+        assert_eq!(errs.omitted_count(), 7);
+    }
+
+    #[test]
+    fn retention_drops_errors_added_before() {
+        for (retention, expected) in [
+            (Retention::First(2), ["Error #0", "Error #1"]),
+            (Retention::Last(2), ["Error #3", "Error #4"]),
+        ] {
+            let mut errs = ErrorStash::new(|| "Summary");
+            for i in 0..5 {
+                errs.push(format!("Error #{i}"));
+            }
+
+            let err: Error = errs
+                .with_retention(retention)
+                .into_result()
+                .unwrap_err();
+
+            assert_eq!(printed_children(&err), expected);
+            assert_eq!(
+                err.to_string(),
+                "Summary (2 errors, and 3 more errors)"
+            );
+        }
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        for i in 0..100 {
+            errs.push(format!("Error #{i}"));
+        }
+
+        let errs = errs.with_retention(Retention::Sample(5));
+        let err: Error = errs.into_result().unwrap_err();
+        assert_eq!(err.to_string(), "Summary (5 errors, and 95 more errors)");
+    }
+
+    #[test]
+    fn works_with_iterator_adapters() {
+        let tokens = ["1", "A", "B", "4", "C"];
+
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::Last(1));
+
+        let numbers: Vec<u32> = tokens
+            .iter()
+            .map(|s| s.parse::<u32>())
+            .stash_err(&mut errs)
+            .collect();
+
+        assert_eq!(numbers, [1, 4]);
+        assert_eq!(errs.errors().len(), 1);
+
+        let result = tokens
+            .iter()
+            .map(|s| s.parse::<u32>())
+            .try_collect_or_stash::<Vec<u32>>(&mut errs);
+
+        assert!(matches!(result, StashedResult::Err(_)));
+
+        let err: Error = errs.into_result().unwrap_err();
+        assert_eq!(err.children().len(), 1);
+        match &*err {
+            ErrorData::Stashed(errs) => assert_eq!(errs.omitted_count(), 5),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn zero_is_treated_as_one() {
+        let err = stash(Retention::First(0), 3);
+        assert_eq!(printed_children(&err), ["Error #0"]);
+        assert_eq!(err.to_string(), "Summary: Error #0 (and 2 more errors)");
+    }
+
+    #[test]
+    fn dropped_errors_are_not_ignored() {
+        let mut errs =
+            ErrorStash::new(|| "Summary").with_retention(Retention::First(1));
+        errs.push_warning("Warning");
+        errs.push("Error");
+
+        assert!(errs.has_errors());
+        assert_eq!(errs.errors().len(), 1);
+
+        let err: Error = errs.into_result().unwrap_err();
+        match &*err {
+            ErrorData::Stashed(errs) => {
+                assert!(errs.has_errors());
+                assert_eq!(errs.omitted_count(), 1);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use core::fmt::Display;
+
+use alloc::{string::String, vec::Vec};
+
+use serde::{Serialize, Serializer};
+
+use crate::{Diagnostic, ErrorData, InnerError, Severity};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Serializes the leaves of the entire tree of an [`Error`]
+/// as SARIF v2.1.0 log, to be uploaded to code scanning dashboards.
+///
+/// Values of this type are created by [`ErrorData::sarif`],
+/// which you can call on [`Error`] as well.
+/// The log contains a single run of the tool
+/// whose name (and, optionally, version) you specify.
+/// Each [`Diagnostic`] of the error becomes a `result` in that run.
+/// The `level` of the result is either `error` or `warning`,
+/// depending on the [`Severity`] of the diagnostic.
+/// The source location of the leaf becomes the `physicalLocation`
+/// of the result.
+/// The summaries and contexts of the ancestors of the leaf
+/// become a `rule` of the tool, identified by those summaries and contexts
+/// (joined by `: `), which the result references via `ruleId`.
+/// Leaves with identical ancestors thus share the same rule.
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// use lazy_errors::prelude::*;
+///
+/// let mut errs = ErrorStash::new(|| "Line too long");
+/// errs.push("Line has 120 characters");
+/// errs.push_warning(Error::from_message("Line has 81 characters"));
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let sarif = err
+///     .sarif("my-linter")
+///     .with_tool_version("1.0.0");
+/// let json = serde_json::to_value(&sarif).unwrap();
+/// assert_eq!(json["version"], "2.1.0");
+///
+/// let run = &json["runs"][0];
+/// assert_eq!(run["tool"]["driver"]["name"], "my-linter");
+/// assert_eq!(run["tool"]["driver"]["version"], "1.0.0");
+///
+/// let rule = &run["tool"]["driver"]["rules"][0];
+/// assert_eq!(rule["id"], "Line too long");
+/// assert_eq!(rule["shortDescription"]["text"], "Line too long");
+///
+/// let result = &run["results"][0];
+/// assert_eq!(result["ruleId"], "Line too long");
+/// assert_eq!(result["ruleIndex"], 0);
+/// assert_eq!(result["level"], "error");
+/// assert_eq!(result["message"]["text"], "Line has 120 characters");
+///
+/// let location = &result["locations"][0]["physicalLocation"];
+/// let file = file!().replace('\\', "/");
+/// assert_eq!(location["artifactLocation"]["uri"], file);
+/// assert!(location["region"]["startLine"].is_u64());
+/// assert!(location["region"]["startColumn"].is_u64());
+///
+/// let result = &run["results"][1];
+/// assert_eq!(result["ruleId"], "Line too long");
+/// assert_eq!(result["level"], "warning");
+/// assert_eq!(result["message"]["text"], "Line has 81 characters");
+/// # }
+/// ```
+///
+/// [`Error`]: crate::Error
+#[derive(Debug)]
+pub struct Sarif<'a, I> {
+    error: &'a ErrorData<I>,
+    tool_name: &'a str,
+    tool_version: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Log<'a> {
+    #[serde(rename = "$schema")]
+    schema:  &'static str,
+    version: &'static str,
+    runs:    [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct Run<'a> {
+    tool:    Tool<'a>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: Driver<'a>,
+}
+
+#[derive(Serialize)]
+struct Driver<'a> {
+    name: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    short_description: Message,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+
+    level:   &'static str,
+    message: Message,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line:   u32,
+    start_column: u32,
+}
+
+impl<'a, I> Sarif<'a, I> {
+    pub(crate) fn new(error: &'a ErrorData<I>, tool_name: &'a str) -> Self {
+        Self {
+            error,
+            tool_name,
+            tool_version: None,
+        }
+    }
+
+    /// Sets the version of the tool that is reported in the log.
+    /// By default, no version is reported.
+    pub fn with_tool_version(mut self, version: &'a str) -> Self {
+        self.tool_version = Some(version);
+        self
+    }
+}
+
+impl<I> Serialize for Sarif<'_, I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut rules: Vec<Rule> = Vec::new();
+        let mut results = Vec::new();
+
+        for diagnostic in self.error.diagnostics() {
+            let rule_id = rule_id(&diagnostic);
+            let rule_index = rule_id
+                .as_ref()
+                .map(|id| rule_index(&mut rules, id));
+
+            results.push(SarifResult {
+                rule_id,
+                rule_index,
+                level: level(diagnostic.severity()),
+                message: Message {
+                    text: diagnostic.message(),
+                },
+                locations: locations(&diagnostic),
+            });
+        }
+
+        let log = Log {
+            schema:  SCHEMA,
+            version: "2.1.0",
+            runs:    [Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: self.tool_name,
+                        version: self.tool_version,
+                        rules,
+                    },
+                },
+                results,
+            }],
+        };
+
+        log.serialize(serializer)
+    }
+}
+
+/// Returns the summaries and contexts of the ancestors of the diagnostic,
+/// joined by `: `, or `None` if the diagnostic has no ancestors.
+fn rule_id<I>(diagnostic: &Diagnostic<'_, I>) -> Option<String> {
+    match diagnostic.context() {
+        [] => None,
+        context => Some(context.join(": ")),
+    }
+}
+
+/// Returns the index of the rule with the given ID,
+/// adding the rule if it does not exist yet.
+fn rule_index(rules: &mut Vec<Rule>, id: &str) -> usize {
+    if let Some(index) = rules
+        .iter()
+        .position(|rule| rule.id == id)
+    {
+        return index;
+    }
+
+    rules.push(Rule {
+        id: id.into(),
+        short_description: Message { text: id.into() },
+    });
+
+    rules.len() - 1
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+fn locations<I>(diagnostic: &Diagnostic<'_, I>) -> Vec<SarifLocation> {
+    diagnostic
+        .location()
+        .into_iter()
+        .map(|location| SarifLocation {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: location.file().replace('\\', "/"),
+                },
+                region: Region {
+                    start_line:   location.line(),
+                    start_column: location.column(),
+                },
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    #[test]
+    fn ad_hoc_error_has_no_rule() {
+        let err: Error = Error::from_message("Oops");
+        let json = serde_json::to_value(err.sarif("tool")).unwrap();
+
+        assert_eq!(
+            json["$schema"],
+            "https://json.schemastore.org/sarif-2.1.0.json"
+        );
+
+        let driver = &json["runs"][0]["tool"]["driver"];
+        assert_eq!(driver["name"], "tool");
+        assert!(driver.get("version").is_none());
+        assert!(driver.get("rules").is_none());
+
+        let result = &json["runs"][0]["results"][0];
+        assert!(result.get("ruleId").is_none());
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["message"]["text"], "Oops");
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn results_share_rules_by_context() {
+        let mut inner = ErrorStash::new(|| "Inner");
+        inner.push("First");
+        inner.push("Second");
+        let inner: Error = inner.into_result().unwrap_err();
+
+        let mut errs = ErrorStash::new(|| "Outer");
+        errs.push(Error::wrap_with(inner, "Context"));
+        errs.push("Third");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let json = serde_json::to_value(err.sarif("tool")).unwrap();
+        let run = &json["runs"][0];
+
+        let rules = &run["tool"]["driver"]["rules"];
+        assert_eq!(rules[0]["id"], "Outer: Context: Inner");
+        assert_eq!(rules[1]["id"], "Outer");
+        assert!(rules.get(2).is_none());
+
+        let results = &run["results"];
+        assert_eq!(results[0]["ruleIndex"], 0);
+        assert_eq!(results[1]["ruleIndex"], 0);
+        assert_eq!(results[2]["ruleIndex"], 1);
+        assert_eq!(results[2]["message"]["text"], "Third");
+    }
+}
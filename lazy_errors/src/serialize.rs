@@ -0,0 +1,421 @@
+use core::fmt::Display;
+
+use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
+
+use crate::{
+    retention::Omitted, AdHocError, Attachments, Error, ErrorData,
+    ErrorLocation, InnerError, NodeKind, OwnedError, OwnedLeaf, Severity,
+    StashWithErrors, StashedErrors, WrappedError,
+};
+
+/// Serializes the entire tree of the error as nested structure.
+///
+/// Each node in the tree is serialized as a struct with a `kind` field,
+/// which is one of `stashed`, `wrapped`, `ad_hoc`, or `leaf`.
+/// Depending on the kind, the struct contains the `summary`, `context`,
+/// or `message` of the error, its `location`, and its children.
+/// The fields of [`Attachments`] (if any) are serialized
+/// as list of key/value pairs named `fields`;
+/// typed attachments are not serialized.
+/// Errors in a list that have been added as [`Severity::Warning`]
+/// have an additional `severity` field set to `warning`,
+/// as do ad-hoc errors that are warnings themselves
+/// (see [`AdHocError::severity`]).
+/// If errors or warnings have been dropped from a list
+/// because of its [`Retention`](crate::Retention),
+/// the list has additional `omitted_errors` and `omitted_warnings` fields
+/// that count those errors and warnings.
+/// If processing has been aborted early because of the
+/// [`FailFast`](crate::FailFast) policy of the list,
+/// the list has an additional `aborted` field set to `true`.
+/// Errors that are not errors from this crate (i.e. leaves)
+/// are serialized by their [`Display`] representation
+/// (and their “pretty” representation, if it differs),
+/// along with the chain of their `core::error::Error::source`s
+/// (if `core::error::Error` or `std::error::Error` is available).
+/// Note that nested errors can only be serialized as nested structures
+/// if the [_inner error type_ `I`](crate::Error#inner-error-type-i)
+/// allows `lazy_errors` to identify them
+/// (see [`InnerError::as_error_tree`]).
+/// Otherwise, they will be serialized as leaves.
+/// Serialized errors can be deserialized as [`OwnedError`].
+///
+/// ```
+/// # #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// # {
+/// use lazy_errors::prelude::*;
+///
+/// let mut errs = ErrorStash::new(|| "Failed to run");
+/// errs.push(Error::wrap_with("Inner error", "Context"));
+/// errs.push(Error::from_message("Ad-hoc error"));
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let json = serde_json::to_value(&err).unwrap();
+/// assert_eq!(json["kind"], "stashed");
+/// assert_eq!(json["summary"], "Failed to run");
+///
+/// let entry = &json["errors"][0];
+/// assert_eq!(entry["location"]["file"], file!());
+/// assert_eq!(entry["error"]["kind"], "wrapped");
+/// assert_eq!(entry["error"]["context"], "Context");
+/// assert_eq!(entry["error"]["inner"]["kind"], "leaf");
+/// assert_eq!(entry["error"]["inner"]["message"], "Inner error");
+///
+/// let entry = &json["errors"][1];
+/// assert_eq!(entry["error"]["kind"], "ad_hoc");
+/// assert_eq!(entry["error"]["message"], "Ad-hoc error");
+/// assert!(entry["error"]["location"]["line"].is_u64());
+/// # }
+/// ```
+impl<I> Serialize for Error<I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let data: &ErrorData<I> = self;
+        data.serialize(serializer)
+    }
+}
+
+impl<I> Serialize for ErrorData<I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        NodeKind::from(self).serialize(serializer)
+    }
+}
+
+impl<I> Serialize for NodeKind<'_, I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match *self {
+            NodeKind::Stashed(errs) => errs.serialize(serializer),
+            NodeKind::Wrapped(err) => err.serialize(serializer),
+            NodeKind::AdHoc(err) => err.serialize(serializer),
+            NodeKind::Leaf(err) => {
+                let leaf = OwnedError::Leaf(OwnedLeaf::from_inner(err));
+                leaf.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<I> Serialize for StashedErrors<I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_stash(
+            serializer,
+            self.summary(),
+            Some(self.attachments()),
+            Entries {
+                errors: self.errors(),
+                locations: self.locations(),
+                attachments: &|i| self.entry_attachments(i),
+                severities: &|i| self.severity(i),
+            },
+            self.omitted(),
+            self.is_aborted(),
+        )
+    }
+}
+
+impl<I> Serialize for StashWithErrors<I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_stash(
+            serializer,
+            self.summary(),
+            None,
+            Entries {
+                errors: self.errors(),
+                locations: self.locations(),
+                attachments: &|i| self.entry_attachments(i),
+                severities: &|i| self.severity(i),
+            },
+            self.omitted(),
+            self.is_limit_reached(),
+        )
+    }
+}
+
+impl<I> Serialize for WrappedError<I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let inner = self.inner();
+        let inner = inner
+            .as_error_tree()
+            .unwrap_or(NodeKind::Leaf(inner));
+
+        let mut s = serializer.serialize_struct("WrappedError", 5)?;
+        s.serialize_field("kind", "wrapped")?;
+        s.serialize_field("context", &self.context())?;
+        let attachments = Some(self.attachments());
+        serialize_fields(&mut s, attachments)?;
+        s.serialize_field("location", self.location())?;
+        s.serialize_field("inner", &inner)?;
+        s.end()
+    }
+}
+
+impl Serialize for AdHocError {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("AdHocError", 5)?;
+        s.serialize_field("kind", "ad_hoc")?;
+        s.serialize_field("message", self.message())?;
+        let attachments = Some(self.attachments());
+        serialize_fields(&mut s, attachments)?;
+        s.serialize_field("location", self.location())?;
+        if self.severity().is_warning() {
+            s.serialize_field("severity", &self.severity())?;
+        } else {
+            s.skip_field("severity")?;
+        }
+        s.end()
+    }
+}
+
+/// A list of errors, their locations, and their attachments,
+/// as stored in [`StashedErrors`] and [`StashWithErrors`].
+struct Entries<'a, I> {
+    errors: &'a [I],
+    locations: &'a [ErrorLocation],
+    attachments: &'a dyn Fn(usize) -> Option<&'a Attachments>,
+    severities: &'a dyn Fn(usize) -> Severity,
+}
+
+/// An error in a list of errors, along with the location at which
+/// it was added to the list and the attachments it was added with.
+struct Entry<'a, I> {
+    error: &'a I,
+    location: &'a ErrorLocation,
+    attachments: Option<&'a Attachments>,
+    severity: Severity,
+}
+
+/// The fields of [`Attachments`], serialized as list of key/value pairs.
+struct Fields<'a>(&'a Attachments);
+
+fn serialize_stash<S, I>(
+    serializer: S,
+    summary: &str,
+    attachments: Option<&Attachments>,
+    entries: Entries<'_, I>,
+    omitted: Omitted,
+    aborted: bool,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    I: Display + InnerError,
+{
+    let mut s = serializer.serialize_struct("StashedErrors", 7)?;
+    s.serialize_field("kind", "stashed")?;
+    s.serialize_field("summary", summary)?;
+    serialize_fields(&mut s, attachments)?;
+    s.serialize_field("errors", &entries)?;
+    serialize_count(&mut s, "omitted_errors", omitted.errors)?;
+    serialize_count(&mut s, "omitted_warnings", omitted.warnings)?;
+    if aborted {
+        s.serialize_field("aborted", &aborted)?;
+    } else {
+        s.skip_field("aborted")?;
+    }
+    s.end()
+}
+
+/// Serializes `count` as struct field named `key`
+/// or skips that struct field if `count` is zero.
+fn serialize_count<S>(
+    s: &mut S,
+    key: &'static str,
+    count: usize,
+) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+{
+    match count {
+        0 => s.skip_field(key),
+        n => s.serialize_field(key, &n),
+    }
+}
+
+/// Serializes the fields of `attachments` as struct field named `fields`
+/// or skips that struct field if there aren't any fields.
+fn serialize_fields<S>(
+    s: &mut S,
+    attachments: Option<&Attachments>,
+) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+{
+    match attachments {
+        Some(attachments) if attachments.fields().next().is_some() => {
+            s.serialize_field("fields", &Fields(attachments))
+        }
+        _ => s.skip_field("fields"),
+    }
+}
+
+impl<I> Serialize for Entries<'_, I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_seq(Some(self.errors.len()))?;
+        let iter = self.errors.iter().zip(self.locations);
+        for (i, (error, location)) in iter.enumerate() {
+            let attachments = (self.attachments)(i);
+            s.serialize_element(&Entry {
+                error,
+                location,
+                attachments,
+                severity: (self.severities)(i),
+            })?;
+        }
+        s.end()
+    }
+}
+
+impl<I> Serialize for Entry<'_, I>
+where
+    I: Display + InnerError,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let error = self
+            .error
+            .as_error_tree()
+            .unwrap_or(NodeKind::Leaf(self.error));
+
+        let mut s = serializer.serialize_struct("Entry", 4)?;
+        s.serialize_field("location", &self.location)?;
+        serialize_fields(&mut s, self.attachments)?;
+        if self.severity.is_warning() {
+            s.serialize_field("severity", &self.severity)?;
+        } else {
+            s.skip_field("severity")?;
+        }
+        s.serialize_field("error", &error)?;
+        s.end()
+    }
+}
+
+impl Serialize for Fields<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.fields())
+    }
+}
+
+/// Serialized in the same format as [`OwnedLocation`](crate::OwnedLocation).
+impl Serialize for ErrorLocation {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Location", 3)?;
+        s.serialize_field("file", self.file())?;
+        s.serialize_field("line", &self.line())?;
+        s.serialize_field("column", &self.column())?;
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    #[test]
+    fn serialize_stash_with_errors() {
+        let mut errs = ErrorStash::new(|| "Summary");
+        let stash: &StashWithErrors = errs.push("Error");
+
+        let json = serde_json::to_value(stash).unwrap();
+        assert_eq!(json["kind"], "stashed");
+        assert_eq!(json["summary"], "Summary");
+        assert_eq!(json["errors"][0]["error"]["kind"], "leaf");
+        assert_eq!(json["errors"][0]["error"]["message"], "Error");
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn serialize_nested_errors_as_nested_structures() {
+        let mut inner = ErrorStash::new(|| "Inner summary");
+        inner.push("Inner error");
+
+        let mut errs = ErrorStash::new(|| "Outer summary");
+        errs.push(inner.into_result().unwrap_err());
+        let err: Error = errs.into_result().unwrap_err();
+
+        let json = serde_json::to_value(&err).unwrap();
+        let inner = &json["errors"][0]["error"];
+        assert_eq!(inner["kind"], "stashed");
+        assert_eq!(inner["summary"], "Inner summary");
+        assert_eq!(inner["errors"][0]["error"]["message"], "Inner error");
+    }
+
+    #[test]
+    #[cfg(feature = "rust-v1.81")]
+    fn serialize_leaf_with_source_chain() {
+        #[derive(thiserror::Error, Debug)]
+        #[error("Outer")]
+        struct Outer(#[source] Middle);
+
+        #[derive(thiserror::Error, Debug)]
+        #[error("Middle")]
+        struct Middle(#[source] Root);
+
+        #[derive(thiserror::Error, Debug)]
+        #[error("Root")]
+        struct Root;
+
+        let err: Error = Error::wrap(Outer(Middle(Root)));
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["inner"]["message"], "Outer");
+        assert_eq!(
+            json["inner"]["sources"],
+            serde_json::json!(["Middle", "Root"])
+        );
+    }
+}
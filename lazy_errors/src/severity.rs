@@ -0,0 +1,110 @@
+use core::fmt::{self, Display};
+
+/// Whether an error in an [`ErrorStash`] is an actual error
+/// or just a warning.
+///
+/// Errors are stashed as [`Severity::Error`] by default,
+/// for example when calling [`push`] or [`or_stash`].
+/// Errors that are stashed via [`push_warning`] or [`or_stash_warning`]
+/// are stashed as [`Severity::Warning`] instead.
+/// Warnings are part of the stash like any other error
+/// and are printed along with the other errors.
+/// However, a stash that contains only warnings is not considered to
+/// have failed: [`ErrorStash::ok`] and [`ErrorStash::into_result`]
+/// will return `Ok(())` in that case, discarding the warnings.
+/// If you want to print the warnings,
+/// call [`ErrorStash::into_result_with_warnings`] instead:
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// use lazy_errors::prelude::*;
+///
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// use lazy_errors::surrogate_error_trait::prelude::*;
+///
+/// let mut errs = ErrorStash::new(|| "Config has issues");
+/// errs.push_warning("Key 'colour' is deprecated");
+/// assert!(errs.ok().ok().is_some());
+///
+/// let warnings: Error = errs
+///     .into_result_with_warnings()
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(
+///     format!("{warnings}"),
+///     "Config has issues: warning: Key 'colour' is deprecated"
+/// );
+///
+/// let mut errs = ErrorStash::new(|| "Config has issues");
+/// errs.push_warning("Key 'colour' is deprecated");
+/// errs.push("Key 'size' is not a number");
+/// assert!(errs.ok().ok().is_none());
+///
+/// let err: Error = errs.into_result().unwrap_err();
+/// assert_eq!(format!("{err}"), "Config has issues (1 error, 1 warning)");
+///
+/// let printed = format!("{err:#}");
+/// let printed = replace_line_numbers(&printed);
+/// assert_eq!(printed, indoc::indoc! {"
+///     Config has issues
+///     - warning: Key 'colour' is deprecated
+///       at src/severity.rs:1234:56
+///     - Key 'size' is not a number
+///       at src/severity.rs:1234:56"});
+/// ```
+///
+/// Variants are ordered by severity, i.e. [`Severity::Warning`]
+/// is less than [`Severity::Error`].
+///
+/// [`ErrorStash`]: crate::ErrorStash
+/// [`ErrorStash::ok`]: crate::ErrorStash::ok
+/// [`ErrorStash::into_result`]: crate::ErrorStash::into_result
+/// [`ErrorStash::into_result_with_warnings`]:
+/// crate::ErrorStash::into_result_with_warnings
+/// [`push`]: crate::ErrorStash::push
+/// [`push_warning`]: crate::ErrorStash::push_warning
+/// [`or_stash`]: crate::OrStash::or_stash
+/// [`or_stash_warning`]: crate::OrStash::or_stash_warning
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Severity {
+    /// Something that should be reported
+    /// but does not cause the stash to fail.
+    Warning,
+
+    /// An actual error. This is the default.
+    Error,
+}
+
+impl Severity {
+    /// Returns `true` if `self` is [`Severity::Error`].
+    pub fn is_error(&self) -> bool {
+        *self == Self::Error
+    }
+
+    /// Returns `true` if `self` is [`Severity::Warning`].
+    pub fn is_warning(&self) -> bool {
+        *self == Self::Warning
+    }
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` would break MSRV
+impl Default for Severity {
+    fn default() -> Self {
+        Self::Error
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
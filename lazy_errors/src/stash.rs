@@ -1,15 +1,22 @@
-use core::fmt::{self, Debug, Display};
-
-use alloc::{
-    boxed::Box,
-    string::{String, ToString},
-    vec::Vec,
+use core::{
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
 };
 
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+#[cfg(feature = "tracing")]
+use tracing_error::{SpanTrace, SpanTraceStatus};
+
 use crate::{
     err,
-    error::{self, Location},
-    Error, StashedResult,
+    error::{self, Extras},
+    retention::{Omitted, Sampler},
+    Attachments, Error, ErrorData, ErrorKind, ErrorLocation, FailFast,
+    Location, Retention, Severity, StashedErrors, StashedResult,
 };
 
 /// Something to push (“stash”) errors into.
@@ -23,6 +30,17 @@ where
 {
     /// Appends an error to this list of errors.
     fn stash(&mut self, error: E) -> &mut StashWithErrors<I>;
+
+    /// Appends an error to this list of errors as warning.
+    fn stash_warning(&mut self, error: E) -> &mut StashWithErrors<I>;
+
+    /// Records that an operation has succeeded
+    /// (see [`FailFast::MaxErrorRatio`]).
+    fn record_success(&mut self);
+
+    /// Returns the list of errors if the limit of its [`FailFast`] policy
+    /// has been reached, `None` otherwise.
+    fn limit_reached(&mut self) -> Option<&mut StashWithErrors<I>>;
 }
 
 /// Something to read errors from.
@@ -33,6 +51,11 @@ where
 pub trait ErrorSource<I> {
     /// Returns all errors that have been added to this list so far.
     fn errors(&self) -> &[I];
+
+    /// Returns the number of errors that have been added to this list
+    /// so far, including errors that have not been retained
+    /// because of its [`Retention`].
+    fn added_count(&self) -> usize;
 }
 
 /// Something that is/wraps a mutable, empty or non-empty list of errors,
@@ -130,8 +153,10 @@ pub trait EnforceErrors<I> {
     doc = r##"
 
 There's also [`IntoEyreResult`](crate::IntoEyreResult)
-which performs a (lossy) conversion to
-[`eyre::Result`](eyre::Result).
+which converts the stash into [`eyre::Result`](eyre::Result),
+keeping the [`Error`] as-is. If you'd rather have the
+[`eyre::Report`](eyre::Report) print the entire tree of errors,
+use [`IntoEyreResultLossy`](crate::IntoEyreResultLossy) instead.
 
  "##
 )]
@@ -147,7 +172,7 @@ where
     F: FnOnce() -> M,
     M: Display,
 {
-    Empty(F),
+    Empty(F, StashOptions<I>),
     WithErrors(StashWithErrors<I>),
 }
 
@@ -166,15 +191,173 @@ where
     doc = r##"
 
 There's also [`IntoEyreReport`](crate::IntoEyreReport)
-which performs a (lossy) conversion to
-[`eyre::Report`](eyre::Report).
+which converts the stash into [`eyre::Report`](eyre::Report),
+keeping the [`Error`] as-is. If you'd rather have the report
+print the entire tree of errors,
+use [`IntoEyreReportLossy`](crate::IntoEyreReportLossy) instead.
 "##
 )]
 #[derive(Debug)]
 pub struct StashWithErrors<I> {
     summary:   Box<str>,
     errors:    Vec<I>,
-    locations: Vec<Location>,
+    locations: Vec<ErrorLocation>,
+
+    /// Empty unless any error has been pushed along with a kind,
+    /// attachments, backtrace, or span trace, or as warning.
+    /// May be shorter than `errors`.
+    extras: Vec<Extras>,
+
+    options: StashOptions<I>,
+}
+
+/// Options that affect how errors are added
+/// to an [`ErrorStash`] or a [`StashWithErrors`].
+///
+/// Options are set by the `with_*` methods of [`ErrorStash`] and
+/// [`StashWithErrors`] and are kept when an [`ErrorStash`]
+/// turns into a [`StashWithErrors`] because an error was added to it.
+/// They are discarded when the stash is converted into an [`Error`],
+/// except for the number of errors that have not been retained
+/// (see [`Retention`]) and whether processing has been aborted early
+/// (see [`FailFast`]).
+#[derive(Debug)]
+pub struct StashOptions<I> {
+    /// `None` unless any option has been set,
+    /// so that stashes without options don't allocate.
+    values: Option<Box<OptionValues<I>>>,
+}
+
+/// The values of [`StashOptions`] that have been set,
+/// along with the state that those options need.
+///
+/// Kept on the heap so that [`StashWithErrors`] stays small.
+#[derive(Debug)]
+struct OptionValues<I> {
+    /// Limits the number of errors that are retained, if set.
+    retention: Option<Retention>,
+
+    /// Counts the errors that have been dropped because of `retention`.
+    omitted: Omitted,
+
+    /// The number of errors at the start of the stash that have been
+    /// dropped because of [`Retention::Last`] but not removed yet.
+    /// They are removed in batches, so that adding an error
+    /// does not have to shift all retained errors each time.
+    front: usize,
+
+    /// Selects the errors to retain for [`Retention::Sample`].
+    sampler: Sampler,
+
+    /// Limits the number of errors before processing is aborted, if set.
+    fail_fast: Option<FailFast>,
+
+    /// Counts the errors (but not the warnings) that have been added
+    /// since `fail_fast` has been set.
+    failures: usize,
+
+    /// Counts the successes that have been recorded
+    /// since `fail_fast` has been set.
+    successes: usize,
+
+    /// Whether the limit of `fail_fast` has been reached.
+    limit_reached: bool,
+
+    /// Emits a `tracing` event when an error is added, if set.
+    #[cfg(feature = "tracing")]
+    trace_push: Option<TracePush<I>>,
+
+    /// Emits a `log` record at the given level
+    /// when an error is added, if set.
+    #[cfg(feature = "log")]
+    log_push: Option<(log::Level, LogPush<I>)>,
+
+    inner: PhantomData<fn(&I)>,
+}
+
+/// Emits a `tracing` event for an error that is added to the stash
+/// with the given summary, at the given location, and as the given severity.
+///
+/// Hooks get passed the error itself because the stash
+/// may not retain it (see [`Retention`]).
+#[cfg(feature = "tracing")]
+type TracePush<I> = fn(&str, &I, Location, Severity);
+
+/// Emits a `log` record at the given level for an error that is added
+/// to the stash with the given summary, at the given location,
+/// and as the given severity.
+#[cfg(feature = "log")]
+type LogPush<I> = fn(&str, &I, Location, Severity, log::Level);
+
+impl<I> Default for StashOptions<I> {
+    fn default() -> Self {
+        Self { values: None }
+    }
+}
+
+impl<I> Default for OptionValues<I> {
+    fn default() -> Self {
+        Self {
+            retention: None,
+            omitted: Omitted::default(),
+            front: 0,
+            sampler: Sampler::default(),
+            fail_fast: None,
+            failures: 0,
+            successes: 0,
+            limit_reached: false,
+            #[cfg(feature = "tracing")]
+            trace_push: None,
+            #[cfg(feature = "log")]
+            log_push: None,
+            inner: PhantomData,
+        }
+    }
+}
+
+impl<I> StashOptions<I> {
+    fn values(&self) -> Option<&OptionValues<I>> {
+        self.values.as_deref()
+    }
+
+    /// Returns the number of errors that have been dropped
+    /// because of the [`Retention`], if any.
+    fn omitted(&self) -> Omitted {
+        self.values()
+            .map(|values| values.omitted)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of errors at the start of the stash
+    /// that have been dropped but not removed yet.
+    fn front(&self) -> usize {
+        self.values()
+            .map(|values| values.front)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the limit of the [`FailFast`] policy,
+    /// if any, has been reached.
+    fn is_limit_reached(&self) -> bool {
+        match self.values() {
+            Some(values) => values.limit_reached,
+            None => false,
+        }
+    }
+
+    /// Counts a success if a [`FailFast`] policy has been set.
+    fn record_success(&mut self) {
+        if let Some(values) = self.values.as_deref_mut() {
+            if values.fail_fast.is_some() {
+                values.successes += 1;
+            }
+        }
+    }
+
+    fn values_mut(&mut self) -> &mut OptionValues<I> {
+        self.values
+            .get_or_insert_with(Default::default)
+    }
 }
 
 impl<F, M, I> Debug for ErrorStash<F, M, I>
@@ -185,7 +368,7 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Empty(_) => write!(f, "ErrorStash(Empty)"),
+            Self::Empty(..) => write!(f, "ErrorStash(Empty)"),
             Self::WithErrors(errs) => {
                 write!(f, "ErrorStash(")?;
                 Debug::fmt(errs, f)?;
@@ -203,7 +386,7 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Empty(_) => display::<I>(f, &[]),
+            Self::Empty(..) => display::<I>(f, &[]),
             Self::WithErrors(errs) => Display::fmt(errs, f),
         }
     }
@@ -223,12 +406,23 @@ where
     fn errors(&self) -> &[I] {
         self.errors()
     }
+
+    fn added_count(&self) -> usize {
+        match self {
+            Self::Empty(..) => 0,
+            Self::WithErrors(errs) => errs.added_count(),
+        }
+    }
 }
 
 impl<I> ErrorSource<I> for StashWithErrors<I> {
     fn errors(&self) -> &[I] {
         self.errors()
     }
+
+    fn added_count(&self) -> usize {
+        self.errors().len() + self.omitted_count()
+    }
 }
 
 impl<E, F, M, I> ErrorSink<E, I> for ErrorStash<F, M, I>
@@ -241,6 +435,24 @@ where
     fn stash(&mut self, err: E) -> &mut StashWithErrors<I> {
         self.push(err)
     }
+
+    #[track_caller]
+    fn stash_warning(&mut self, err: E) -> &mut StashWithErrors<I> {
+        self.push_warning(err)
+    }
+
+    fn record_success(&mut self) {
+        self.record_success();
+    }
+
+    fn limit_reached(&mut self) -> Option<&mut StashWithErrors<I>> {
+        match self {
+            ErrorStash::WithErrors(stash) if stash.is_limit_reached() => {
+                Some(stash)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<E, I> ErrorSink<E, I> for StashWithErrors<I>
@@ -251,6 +463,22 @@ where
     fn stash(&mut self, err: E) -> &mut StashWithErrors<I> {
         self.push(err)
     }
+
+    #[track_caller]
+    fn stash_warning(&mut self, err: E) -> &mut StashWithErrors<I> {
+        self.push_warning(err)
+    }
+
+    fn record_success(&mut self) {
+        self.record_success();
+    }
+
+    fn limit_reached(&mut self) -> Option<&mut StashWithErrors<I>> {
+        match self.is_limit_reached() {
+            true => Some(self),
+            false => None,
+        }
+    }
 }
 
 impl<F, M, I> EnforceErrors<I> for ErrorStash<F, M, I>
@@ -262,7 +490,7 @@ where
     #[track_caller]
     fn enforce_errors(&mut self) -> &mut StashWithErrors<I> {
         match self {
-            ErrorStash::Empty(_) => self.stash(err!("INTERNAL ERROR")),
+            ErrorStash::Empty(..) => self.stash(err!("INTERNAL ERROR")),
             ErrorStash::WithErrors(stash) => stash,
         }
     }
@@ -284,15 +512,29 @@ where
 {
     fn from(stash: ErrorStash<F, M, I>) -> Self {
         match stash {
-            ErrorStash::Empty(_) => Ok(()),
-            ErrorStash::WithErrors(stash) => Err(stash.into()),
+            ErrorStash::WithErrors(stash) if stash.has_errors() => {
+                Err(stash.into())
+            }
+            _ => Ok(()),
         }
     }
 }
 
 impl<I> From<StashWithErrors<I>> for Error<I> {
-    fn from(stash: StashWithErrors<I>) -> Self {
-        Error::from_stash(stash.summary, stash.errors, stash.locations)
+    fn from(mut stash: StashWithErrors<I>) -> Self {
+        stash.compact();
+        let omitted = stash.options.omitted();
+        let is_aborted = stash.options.is_limit_reached();
+        let errs = StashedErrors::from_error_locations(
+            stash.summary,
+            stash.errors,
+            stash.locations,
+        )
+        .with_extras(stash.extras)
+        .with_omitted(omitted)
+        .with_aborted(is_aborted);
+
+        ErrorData::Stashed(errs).into()
     }
 }
 
@@ -305,7 +547,7 @@ where
     /// that will be evaluated when the first error (if any) is added
     /// to the stash.
     pub fn new(f: F) -> Self {
-        Self::Empty(f)
+        Self::Empty(f, StashOptions::default())
     }
 
     /// Adds an error to this stash.
@@ -319,19 +561,86 @@ where
     where
         E: Into<I>,
     {
+        self.push_entry(err.into(), Extras::default())
+    }
+
+    /// Adds an error to this stash and attaches a user-defined kind to it.
+    ///
+    /// Behaves like [`push`](Self::push) otherwise.
+    /// Please take a look at [`ErrorKind`] for details and an example.
+    #[track_caller]
+    pub fn push_with_kind<E, K>(
+        &mut self,
+        err: E,
+        kind: K,
+    ) -> &mut StashWithErrors<I>
+    where
+        E: Into<I>,
+        K: ErrorKind,
+    {
+        let extras = Extras {
+            kind: Some(Box::new(kind)),
+            ..Extras::default()
+        };
+        self.push_entry(err.into(), extras)
+    }
+
+    /// Adds an error to this stash and attaches [`Attachments`] to it.
+    ///
+    /// Behaves like [`push`](Self::push) otherwise.
+    /// Please take a look at [`Attachments`] for details and an example.
+    #[track_caller]
+    pub fn push_with_attachments<E>(
+        &mut self,
+        err: E,
+        attachments: Attachments,
+    ) -> &mut StashWithErrors<I>
+    where
+        E: Into<I>,
+    {
+        let extras = Extras {
+            attachments,
+            ..Extras::default()
+        };
+        self.push_entry(err.into(), extras)
+    }
+
+    /// Adds an error to this stash as [`Severity::Warning`].
+    ///
+    /// Warnings are printed along with all other errors in this stash,
+    /// but they do not cause this stash to fail
+    /// (see [`ok`](Self::ok) and [`into_result`](Self::into_result)).
+    /// Behaves like [`push`](Self::push) otherwise.
+    /// Please take a look at [`Severity`] for details and an example.
+    #[track_caller]
+    pub fn push_warning<E>(&mut self, err: E) -> &mut StashWithErrors<I>
+    where
+        E: Into<I>,
+    {
+        let extras = Extras {
+            severity: Severity::Warning,
+            ..Extras::default()
+        };
+        self.push_entry(err.into(), extras)
+    }
+
+    /// Adds an error along with its [`Extras`] to this stash.
+    #[track_caller]
+    fn push_entry(
+        &mut self,
+        error: I,
+        extras: Extras,
+    ) -> &mut StashWithErrors<I> {
         // We need to move out of `&mut self`
         // because we want to call `f()` which is `FnOnce()`.
 
-        let mut swap = Self::WithErrors(StashWithErrors {
-            summary:   String::new().into_boxed_str(),
-            errors:    vec![],
-            locations: vec![],
-        });
+        let mut swap = Self::WithErrors(StashWithErrors::dummy(""));
 
         core::mem::swap(self, &mut swap);
-        *self = ErrorStash::WithErrors(swap.push_and_convert(err));
+        *self =
+            ErrorStash::WithErrors(swap.push_entry_and_convert(error, extras));
         match self {
-            ErrorStash::Empty(_) => unreachable!(),
+            ErrorStash::Empty(..) => unreachable!(),
             ErrorStash::WithErrors(stash_with_errors) => stash_with_errors,
         }
     }
@@ -376,10 +685,23 @@ where
     where
         E: Into<I>,
     {
+        self.push_entry_and_convert(err.into(), Extras::default())
+    }
+
+    /// Adds an error along with its [`Extras`] to this stash,
+    /// consumes `self`, and returns the inner [`StashWithErrors`] by value.
+    #[track_caller]
+    fn push_entry_and_convert(
+        self,
+        error: I,
+        extras: Extras,
+    ) -> StashWithErrors<I> {
         match self {
-            ErrorStash::Empty(f) => StashWithErrors::from(f(), err),
+            ErrorStash::Empty(f, options) => {
+                StashWithErrors::from_entry(f(), error, extras, options)
+            }
             ErrorStash::WithErrors(mut stash) => {
-                stash.push(err);
+                stash.push_entry(error, extras);
                 stash
             }
         }
@@ -402,7 +724,7 @@ where
     /// ```
     pub fn is_empty(&self) -> bool {
         match self {
-            ErrorStash::Empty(_) => true,
+            ErrorStash::Empty(..) => true,
             ErrorStash::WithErrors(_) => false,
         }
     }
@@ -429,12 +751,13 @@ where
     /// Such transitive children will _not_ be returned from this method.
     pub fn errors(&self) -> &[I] {
         match self {
-            ErrorStash::Empty(_) => &[],
+            ErrorStash::Empty(..) => &[],
             ErrorStash::WithErrors(stash) => stash.errors(),
         }
     }
 
-    /// Returns `Ok(())` if the stash is empty,
+    /// Returns `Ok(())` if the stash is empty (or contains only
+    /// [warnings](crate::Severity::Warning)),
     /// otherwise returns [`StashedResult::Err`].
     ///
     /// This method basically allows you to use the `?` operator
@@ -520,13 +843,16 @@ where
     /// [`try2!`]: crate::try2!
     pub fn ok(&mut self) -> StashedResult<(), I> {
         match self {
-            ErrorStash::Empty(_) => StashedResult::Ok(()),
-            ErrorStash::WithErrors(errs) => StashedResult::Err(errs),
+            ErrorStash::WithErrors(errs) if errs.has_errors() => {
+                StashedResult::failed(errs)
+            }
+            _ => StashedResult::Ok(()),
         }
     }
 
-    /// Returns `Ok(())` if the stash is empty, otherwise returns an `Err`
-    /// containing all errors from this stash.
+    /// Returns `Ok(())` if the stash is empty (or contains only
+    /// [warnings](crate::Severity::Warning)), otherwise returns an `Err`
+    /// containing all errors (and warnings) from this stash.
     ///
     /// You can usually call `into` instead of this method.
     /// This method actually does nothing else besides specifying
@@ -569,6 +895,130 @@ where
     pub fn into_result(self) -> Result<(), Error<I>> {
         self.into()
     }
+
+    /// Returns `Err` containing all errors and warnings from this stash
+    /// if this stash contains at least one [`Severity::Error`].
+    /// Otherwise, returns `Ok(Some(warnings))` if this stash contains
+    /// only [`Severity::Warning`]s, or `Ok(None)` if it is empty.
+    ///
+    /// This method is similar to [`into_result`](Self::into_result),
+    /// except that warnings are not discarded if there are no errors.
+    /// In both the `Err` and the `Ok(Some(_))` case,
+    /// the [`Error`] will hold the [`ErrorData::Stashed`] variant.
+    /// Please take a look at [`Severity`] for an example.
+    ///
+    /// [`ErrorData::Stashed`]: crate::ErrorData::Stashed
+    pub fn into_result_with_warnings(
+        self,
+    ) -> Result<Option<Error<I>>, Error<I>> {
+        match self {
+            ErrorStash::Empty(..) => Ok(None),
+            ErrorStash::WithErrors(stash) if stash.has_errors() => {
+                Err(stash.into())
+            }
+            ErrorStash::WithErrors(stash) => Ok(Some(stash.into())),
+        }
+    }
+
+    /// Returns `true` if at least one error in this stash
+    /// has been added as [`Severity::Error`].
+    ///
+    /// In contrast to [`is_empty`](Self::is_empty), this method returns
+    /// `false` if this stash contains nothing but warnings.
+    pub fn has_errors(&self) -> bool {
+        match self {
+            ErrorStash::Empty(..) => false,
+            ErrorStash::WithErrors(stash) => stash.has_errors(),
+        }
+    }
+
+    /// Emits a `tracing` event whenever an error is added to this stash.
+    ///
+    /// Please take a look at [`StashWithErrors::with_tracing`]
+    /// for details and an example.
+    #[cfg(feature = "tracing")]
+    pub fn with_tracing(mut self) -> Self
+    where
+        I: Display,
+    {
+        self.options_mut()
+            .values_mut()
+            .trace_push = Some(crate::trace::trace_push::<I>);
+        self
+    }
+
+    /// Emits a `log` record at the given `level`
+    /// whenever an error is added to this stash.
+    ///
+    /// Please take a look at [`StashWithErrors::with_logging`]
+    /// for details and an example.
+    #[cfg(feature = "log")]
+    pub fn with_logging(mut self, level: log::Level) -> Self
+    where
+        I: Display,
+    {
+        self.options_mut().values_mut().log_push =
+            Some((level, crate::logging::log_push::<I>));
+        self
+    }
+
+    /// Limits the number of errors that this stash retains.
+    ///
+    /// Please take a look at [`Retention`] for details and an example.
+    pub fn with_retention(self, retention: Retention) -> Self {
+        match self {
+            ErrorStash::Empty(f, mut options) => {
+                options.values_mut().retention = Some(retention);
+                ErrorStash::Empty(f, options)
+            }
+            ErrorStash::WithErrors(stash) => {
+                ErrorStash::WithErrors(stash.with_retention(retention))
+            }
+        }
+    }
+
+    /// Makes this stash stop processing once the limit
+    /// of the `fail_fast` policy has been reached.
+    ///
+    /// Please take a look at [`FailFast`] for details and an example.
+    pub fn with_fail_fast(self, fail_fast: FailFast) -> Self {
+        match self {
+            ErrorStash::Empty(f, mut options) => {
+                options.values_mut().fail_fast = Some(fail_fast);
+                ErrorStash::Empty(f, options)
+            }
+            ErrorStash::WithErrors(stash) => {
+                ErrorStash::WithErrors(stash.with_fail_fast(fail_fast))
+            }
+        }
+    }
+
+    /// Records that an operation has succeeded,
+    /// which is taken into account by [`FailFast::MaxErrorRatio`].
+    ///
+    /// Does nothing unless a [`FailFast`] policy has been set.
+    /// Please take a look at [`FailFast`] for details.
+    pub fn record_success(&mut self) {
+        self.options_mut().record_success();
+    }
+
+    /// Returns `true` if the limit of the [`FailFast`] policy
+    /// of this stash has been reached.
+    ///
+    /// Please take a look at [`FailFast`] for details and an example.
+    pub fn is_limit_reached(&self) -> bool {
+        match self {
+            ErrorStash::Empty(..) => false,
+            ErrorStash::WithErrors(stash) => stash.is_limit_reached(),
+        }
+    }
+
+    fn options_mut(&mut self) -> &mut StashOptions<I> {
+        match self {
+            ErrorStash::Empty(_, options) => options,
+            ErrorStash::WithErrors(stash) => &mut stash.options,
+        }
+    }
 }
 
 impl<I> StashWithErrors<I> {
@@ -581,11 +1031,32 @@ impl<I> StashWithErrors<I> {
         M: Display,
         E: Into<I>,
     {
-        Self {
-            summary:   summary.to_string().into(),
-            errors:    vec![error.into()],
-            locations: vec![error::location()],
-        }
+        let options = StashOptions::default();
+        Self::from_entry(summary, error.into(), Extras::default(), options)
+    }
+
+    /// Creates a [`StashWithErrors`] that has the supplied `options`
+    /// and contains the supplied error along with its [`Extras`].
+    #[track_caller]
+    fn from_entry<M>(
+        summary: M,
+        error: I,
+        extras: Extras,
+        options: StashOptions<I>,
+    ) -> Self
+    where
+        M: Display,
+    {
+        let mut stash = Self {
+            summary: summary.to_string().into(),
+            errors: vec![],
+            locations: vec![],
+            extras: vec![],
+            options,
+        };
+
+        stash.push_entry(error, extras);
+        stash
     }
 
     /// Adds an error into the stash.
@@ -594,11 +1065,447 @@ impl<I> StashWithErrors<I> {
     where
         E: Into<I>,
     {
-        self.errors.push(err.into());
-        self.locations.push(error::location());
+        self.push_entry(err.into(), Extras::default())
+    }
+
+    /// Adds an error into the stash and attaches a user-defined kind to it.
+    ///
+    /// Please take a look at [`ErrorKind`] for details and an example.
+    #[track_caller]
+    pub fn push_with_kind<E, K>(
+        &mut self,
+        err: E,
+        kind: K,
+    ) -> &mut StashWithErrors<I>
+    where
+        E: Into<I>,
+        K: ErrorKind,
+    {
+        let extras = Extras {
+            kind: Some(Box::new(kind)),
+            ..Extras::default()
+        };
+        self.push_entry(err.into(), extras)
+    }
+
+    /// Adds an error into the stash and attaches [`Attachments`] to it.
+    ///
+    /// Please take a look at [`Attachments`] for details and an example.
+    #[track_caller]
+    pub fn push_with_attachments<E>(
+        &mut self,
+        err: E,
+        attachments: Attachments,
+    ) -> &mut StashWithErrors<I>
+    where
+        E: Into<I>,
+    {
+        let extras = Extras {
+            attachments,
+            ..Extras::default()
+        };
+        self.push_entry(err.into(), extras)
+    }
+
+    /// Adds an error into the stash as [`Severity::Warning`].
+    ///
+    /// Please take a look at [`Severity`] for details and an example.
+    #[track_caller]
+    pub fn push_warning<E>(&mut self, err: E) -> &mut StashWithErrors<I>
+    where
+        E: Into<I>,
+    {
+        let extras = Extras {
+            severity: Severity::Warning,
+            ..Extras::default()
+        };
+        self.push_entry(err.into(), extras)
+    }
+
+    /// Emits a `tracing` event whenever an error is added to this stash.
+    ///
+    /// The event is emitted at the `ERROR` level,
+    /// or at the `WARN` level if the error was added as
+    /// [`Severity::Warning`], and its target is `lazy_errors`.
+    /// The message of the event is the error that was added.
+    /// The event has a `summary` field that contains the
+    /// [summary](Self::summary) of this stash and a `location` field
+    /// that contains the source location at which the error was added.
+    ///
+    /// ```
+    /// use lazy_errors::prelude::*;
+    ///
+    /// let mut errs = ErrorStash::new(|| "Failed to parse").with_tracing();
+    ///
+    /// // Emits an `ERROR` event with the message `Input is empty`,
+    /// // `summary="Failed to parse"`, and the location of this line.
+    /// errs.push("Input is empty");
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn with_tracing(mut self) -> Self
+    where
+        I: Display,
+    {
+        self.options.values_mut().trace_push =
+            Some(crate::trace::trace_push::<I>);
+        self
+    }
+
+    /// Emits a `log` record at the given `level`
+    /// whenever an error is added to this stash.
+    ///
+    /// Errors added as [`Severity::Warning`] are logged
+    /// at the `Warn` level at most.
+    /// The record has the same target as the records
+    /// emitted by [`Error::log`](crate::ErrorData::log), i.e. the target
+    /// is derived from the source location at which the error was added.
+    /// The message of the record is the error that was added.
+    /// The record has a `summary` key that contains the
+    /// [summary](Self::summary) of this stash and a `location` key
+    /// that contains the source location at which the error was added.
+    ///
+    /// This allows you to follow the progress of long-running loops,
+    /// for example when using [`stash_err`](crate::StashErr::stash_err):
+    ///
+    /// ```
+    /// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    /// use lazy_errors::prelude::*;
+    ///
+    /// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    /// use lazy_errors::surrogate_error_trait::prelude::*;
+    ///
+    /// use log::Level;
+    ///
+    /// let mut errs =
+    ///     ErrorStash::new(|| "Failed to parse").with_logging(Level::Error);
+    ///
+    /// // Logs `invalid digit found in string` and `summary=Failed to parse`
+    /// // as soon as the error was encountered.
+    /// let numbers: Vec<u32> = ["1", "X", "3"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<u32>())
+    ///     .stash_err(&mut errs)
+    ///     .collect();
+    ///
+    /// assert_eq!(numbers, [1, 3]);
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn with_logging(mut self, level: log::Level) -> Self
+    where
+        I: Display,
+    {
+        self.options.values_mut().log_push =
+            Some((level, crate::logging::log_push::<I>));
+        self
+    }
+
+    /// Limits the number of errors that this stash retains,
+    /// dropping errors immediately if this stash contains too many errors
+    /// already.
+    ///
+    /// Please take a look at [`Retention`] for details and an example.
+    pub fn with_retention(mut self, retention: Retention) -> Self {
+        self.options.values_mut().retention = Some(retention);
+        self.shrink(retention);
+        self
+    }
+
+    /// Makes this stash stop processing once the limit
+    /// of the `fail_fast` policy has been reached,
+    /// taking into account the errors that this stash contains already.
+    ///
+    /// Please take a look at [`FailFast`] for details and an example.
+    pub fn with_fail_fast(mut self, fail_fast: FailFast) -> Self {
+        let failures = (0..self.errors().len())
+            .filter(|&i| !self.severity(i).is_warning())
+            .count()
+            + self.options.omitted().errors;
+
+        let values = self.options.values_mut();
+        values.fail_fast = Some(fail_fast);
+        values.failures = failures;
+        values.limit_reached |=
+            fail_fast.is_reached(values.failures, values.successes);
         self
     }
 
+    /// Records that an operation has succeeded,
+    /// which is taken into account by [`FailFast::MaxErrorRatio`].
+    ///
+    /// Does nothing unless a [`FailFast`] policy has been set.
+    /// Please take a look at [`FailFast`] for details.
+    pub fn record_success(&mut self) {
+        self.options.record_success();
+    }
+
+    /// Returns `true` if the limit of the [`FailFast`] policy
+    /// of this stash has been reached.
+    ///
+    /// Please take a look at [`FailFast`] for details and an example.
+    pub fn is_limit_reached(&self) -> bool {
+        self.options.is_limit_reached()
+    }
+
+    /// Adds an error along with its [`Extras`] into the stash.
+    ///
+    /// Notifies the hooks set in the [`StashOptions`]
+    /// and counts the error for the [`FailFast`] policy, if any.
+    /// Then stores the error unless the [`Retention`] drops it.
+    /// Backtraces and span traces are captured
+    /// only if the error is actually stored.
+    #[track_caller]
+    fn push_entry(&mut self, error: I, extras: Extras) -> &mut Self {
+        let location = error::location();
+        let severity = extras.severity;
+
+        #[cfg(any(feature = "tracing", feature = "log"))]
+        self.notify(&error, location, severity);
+
+        self.count_failure(severity);
+
+        let index = match self.slot() {
+            Some(index) => index,
+            None => {
+                self.options
+                    .values_mut()
+                    .omitted
+                    .add(severity);
+                return self;
+            }
+        };
+
+        #[allow(unused_mut)] // Only used if traces can be captured
+        let mut extras = extras;
+
+        #[cfg(feature = "backtrace")]
+        {
+            let backtrace = Backtrace::capture();
+            if backtrace.status() == BacktraceStatus::Captured {
+                extras.backtrace = Some(backtrace);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let span_trace = SpanTrace::capture();
+            if span_trace.status() == SpanTraceStatus::CAPTURED {
+                extras.span_trace = Some(span_trace);
+            }
+        }
+
+        self.store(index, error, location, extras);
+        self
+    }
+
+    /// Notifies the hooks set in the [`StashOptions`]
+    /// that an error is being added to this stash.
+    #[cfg(any(feature = "tracing", feature = "log"))]
+    fn notify(&self, error: &I, location: Location, severity: Severity) {
+        let values = match self.options.values() {
+            Some(values) => values,
+            None => return,
+        };
+
+        #[cfg(feature = "tracing")]
+        if let Some(trace_push) = values.trace_push {
+            trace_push(&self.summary, error, location, severity);
+        }
+
+        #[cfg(feature = "log")]
+        if let Some((level, log_push)) = values.log_push {
+            log_push(&self.summary, error, location, severity, level);
+        }
+    }
+
+    /// Counts an error that is being added, unless it is a warning,
+    /// and checks whether the limit of the [`FailFast`] policy,
+    /// if any, has been reached.
+    fn count_failure(&mut self, severity: Severity) {
+        if severity.is_warning() {
+            return;
+        }
+
+        let values = match self.options.values.as_deref_mut() {
+            Some(values) => values,
+            None => return,
+        };
+
+        if let Some(fail_fast) = values.fail_fast {
+            values.failures += 1;
+            values.limit_reached |=
+                fail_fast.is_reached(values.failures, values.successes);
+        }
+    }
+
+    /// Returns the index at which the error that is being added
+    /// shall be stored, or `None` if the [`Retention`] drops it.
+    ///
+    /// If the index is less than `self.errors.len()`,
+    /// the error at that index is dropped in favor of the new one.
+    fn slot(&mut self) -> Option<usize> {
+        let len = self.errors.len();
+        let values = match self.options.values.as_deref_mut() {
+            Some(values) => values,
+            None => return Some(len),
+        };
+
+        let retention = match values.retention {
+            Some(retention) => retention,
+            None => return Some(len),
+        };
+
+        let retained = len - values.front;
+        if retained < retention.limit() {
+            return Some(len);
+        }
+
+        match retention {
+            Retention::First(_) => None,
+            Retention::Last(_) => {
+                let front = values.front;
+                values.front += 1;
+
+                let severity = self.stored_severity(front);
+                if let Some(extras) = self.extras.get_mut(front) {
+                    *extras = Extras::default();
+                }
+                self.options
+                    .values_mut()
+                    .omitted
+                    .add(severity);
+
+                if self.options.front() >= retention.limit() {
+                    self.compact();
+                }
+
+                Some(self.errors.len())
+            }
+            Retention::Sample(_) => {
+                // Reservoir sampling: The error that is being added
+                // replaces a random error with a probability of `limit/seen`.
+                let seen = retained + values.omitted.count() + 1;
+                match values.sampler.below(seen) {
+                    i if i < retention.limit() => Some(i),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Stores an error at `index`, which is either
+    /// the index of an error to replace or `self.errors.len()`.
+    fn store(
+        &mut self,
+        index: usize,
+        error: I,
+        location: Location,
+        extras: Extras,
+    ) {
+        if index < self.errors.len() {
+            let severity = self.stored_severity(index);
+            self.options
+                .values_mut()
+                .omitted
+                .add(severity);
+
+            self.errors[index] = error;
+            self.locations[index] = location.into();
+        } else {
+            self.errors.push(error);
+            self.locations.push(location.into());
+        }
+
+        if !extras.is_empty() {
+            if self.extras.len() <= index {
+                self.extras
+                    .resize_with(index + 1, Extras::default);
+            }
+            self.extras[index] = extras;
+        } else if let Some(stored) = self.extras.get_mut(index) {
+            *stored = Extras::default();
+        }
+    }
+
+    /// Drops errors until this stash retains
+    /// no more errors than allowed by `retention`.
+    ///
+    /// Used when `retention` is set on a stash that contains errors already.
+    fn shrink(&mut self, retention: Retention) {
+        self.compact();
+
+        let len = self.errors.len();
+        let limit = retention.limit();
+        if len <= limit {
+            return;
+        }
+
+        let mut keep = vec![false; len];
+        match retention {
+            Retention::First(_) => keep[..limit].fill(true),
+            Retention::Last(_) => keep[len - limit..].fill(true),
+            Retention::Sample(_) => {
+                let values = self.options.values_mut();
+                let omitted = values.omitted.count();
+                let mut slots: Vec<usize> = (0..limit).collect();
+                for index in limit..len {
+                    let seen = omitted + index + 1;
+                    let i = values.sampler.below(seen);
+                    if i < limit {
+                        slots[i] = index;
+                    }
+                }
+                for index in slots {
+                    keep[index] = true;
+                }
+            }
+        }
+
+        for (index, _) in keep
+            .iter()
+            .enumerate()
+            .filter(|(_, &k)| !k)
+        {
+            let severity = self.stored_severity(index);
+            self.options
+                .values_mut()
+                .omitted
+                .add(severity);
+        }
+
+        let mut flags = keep.iter();
+        self.errors
+            .retain(|_| *flags.next().unwrap());
+        let mut flags = keep.iter();
+        self.locations
+            .retain(|_| *flags.next().unwrap());
+        let mut flags = keep.iter();
+        self.extras
+            .retain(|_| *flags.next().unwrap());
+    }
+
+    /// Removes the errors that have been dropped from the start
+    /// of this stash because of [`Retention::Last`].
+    fn compact(&mut self) {
+        let front = match self.options.values.as_deref_mut() {
+            Some(values) => core::mem::take(&mut values.front),
+            None => return,
+        };
+
+        self.errors.drain(..front);
+        self.locations.drain(..front);
+        self.extras
+            .drain(..Ord::min(front, self.extras.len()));
+    }
+
+    /// Returns the [`Severity`] of the error at `index`,
+    /// including errors that have been dropped but not removed yet.
+    fn stored_severity(&self, index: usize) -> Severity {
+        self.extras
+            .get(index)
+            .map(|extras| extras.severity)
+            .unwrap_or_default()
+    }
+
     /// Returns all errors that have been put into this stash so far.
     ///
     /// Note that this method only returns errors that have been
@@ -608,7 +1515,91 @@ impl<I> StashWithErrors<I> {
     /// which stored another level of errors.
     /// Such transitive children will _not_ be returned from this method.
     pub fn errors(&self) -> &[I] {
-        &self.errors
+        &self.errors[self.options.front()..]
+    }
+
+    /// Returns the message that summarizes all errors in this list.
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Returns the source locations at which the [`errors`](Self::errors)
+    /// were added to this list.
+    /// Each location belongs to the error at the same index.
+    pub fn locations(&self) -> &[ErrorLocation] {
+        &self.locations[self.options.front()..]
+    }
+
+    /// Returns the backtrace that was captured when the error at `index`
+    /// was added to this list, if any.
+    ///
+    /// Backtraces are only captured if enabled by the
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables
+    /// (see [`Backtrace::capture`]).
+    #[cfg(feature = "backtrace")]
+    pub fn entry_backtrace(&self, index: usize) -> Option<&Backtrace> {
+        self.entry_extras(index)
+            .and_then(|extras| extras.backtrace.as_ref())
+    }
+
+    /// Returns the span trace that was captured when the error at `index`
+    /// was added to this list, if any.
+    ///
+    /// Span traces are only captured if a `tracing_error::ErrorLayer`
+    /// is installed (see [`SpanTrace::capture`]).
+    #[cfg(feature = "tracing")]
+    pub fn entry_span_trace(&self, index: usize) -> Option<&SpanTrace> {
+        self.entry_extras(index)
+            .and_then(|extras| extras.span_trace.as_ref())
+    }
+
+    /// Returns the attachments that have been attached to the error
+    /// at `index` when it was added to this list, if any.
+    pub fn entry_attachments(&self, index: usize) -> Option<&Attachments> {
+        self.entry_extras(index)
+            .map(|extras| &extras.attachments)
+    }
+
+    /// Returns the [`Severity`] with which the error at `index`
+    /// was added to this stash.
+    ///
+    /// Returns [`Severity::Error`] if `index` is out of bounds.
+    pub fn severity(&self, index: usize) -> Severity {
+        self.stored_severity(self.options.front() + index)
+    }
+
+    /// Returns `true` if at least one error in this stash
+    /// has been added as [`Severity::Error`],
+    /// i.e. if this stash contains more than just warnings.
+    pub fn has_errors(&self) -> bool {
+        let extras = self.extras.get(self.options.front()..);
+        let warnings = extras
+            .unwrap_or_default()
+            .iter()
+            .filter(|extras| extras.severity.is_warning())
+            .count();
+
+        warnings < self.errors().len() || self.options.omitted().errors > 0
+    }
+
+    /// Returns the [`Extras`] of the error at `index`, if any.
+    fn entry_extras(&self, index: usize) -> Option<&Extras> {
+        self.extras
+            .get(self.options.front() + index)
+    }
+
+    /// Returns the number of errors that have been added to this stash
+    /// but have not been retained because of its [`Retention`].
+    /// Please take a look at [`Retention`] for details.
+    pub fn omitted_count(&self) -> usize {
+        self.options.omitted().count()
+    }
+
+    /// Returns the number of errors and warnings that have not been
+    /// retained because of the [`Retention`] of this stash.
+    #[cfg(feature = "serde")]
+    pub(crate) fn omitted(&self) -> Omitted {
+        self.options.omitted()
     }
 
     /// ⚠️ Do not use this method! ⚠️
@@ -637,15 +1628,22 @@ impl<I> StashWithErrors<I> {
         // But better print a specific error message in case it does.
         const WARNING: &str = "Internal error: Error info cleared by take()";
 
-        let mut swap_with = Self {
-            summary:   WARNING.to_string().into_boxed_str(),
-            errors:    vec![],
-            locations: vec![],
-        };
-
+        let mut swap_with = Self::dummy(WARNING);
         core::mem::swap(&mut swap_with, self);
         swap_with
     }
+
+    /// Returns an invalid [`StashWithErrors`] that contains no errors.
+    /// Must only be used as placeholder when swapping values.
+    fn dummy(summary: &str) -> Self {
+        Self {
+            summary:   summary.to_string().into_boxed_str(),
+            errors:    vec![],
+            locations: vec![],
+            extras:    vec![],
+            options:   StashOptions::default(),
+        }
+    }
 }
 
 fn display<I>(f: &mut fmt::Formatter<'_>, errors: &[I]) -> fmt::Result {
@@ -799,4 +1797,71 @@ mod tests {
         let msg = format!("{err}");
         assert_eq!("Failure: External error", &msg);
     }
+
+    #[test]
+    fn stash_with_only_warnings_is_ok() {
+        let mut errs = ErrorStash::new(|| "Failure");
+        errs.push_warning("First warning");
+        errs.push_warning("Second warning");
+
+        assert!(!errs.is_empty());
+        assert!(!errs.has_errors());
+        assert!(errs.ok().ok().is_some());
+        assert!(errs.into_result().is_ok());
+    }
+
+    #[test]
+    fn stash_with_warnings_and_errors_fails() {
+        let mut errs = ErrorStash::new(|| "Failure");
+        errs.push_warning("Warning");
+        errs.push("Error");
+
+        assert!(errs.has_errors());
+        assert!(errs.ok().ok().is_none());
+
+        let err = errs.into_result().unwrap_err();
+        assert_eq!(format!("{err}"), "Failure (1 error, 1 warning)");
+    }
+
+    #[test]
+    fn stash_returns_warnings_if_there_are_no_errors() {
+        let errs = ErrorStash::new(|| "Failure");
+        assert!(errs
+            .into_result_with_warnings()
+            .unwrap()
+            .is_none());
+
+        let mut errs = ErrorStash::new(|| "Failure");
+        errs.push_warning("Warning");
+        let warnings: Error = errs
+            .into_result_with_warnings()
+            .unwrap()
+            .unwrap();
+        assert_eq!(format!("{warnings}"), "Failure: warning: Warning");
+
+        let mut errs = ErrorStash::new(|| "Failure");
+        errs.push_warning("Warning");
+        errs.push("Error");
+        let err: Error = errs
+            .into_result_with_warnings()
+            .unwrap_err();
+        assert_eq!(err.children().len(), 2);
+    }
+
+    #[test]
+    fn try2_does_not_return_early_on_warnings() {
+        fn run(fail: bool) -> Result<u8, Error> {
+            let mut errs = ErrorStash::new(|| "Failure");
+            errs.push_warning("Warning");
+            if fail {
+                errs.push("Error");
+            }
+
+            try2!(errs.ok());
+            Ok(42)
+        }
+
+        assert_eq!(run(false).unwrap(), 42);
+        assert_eq!(run(true).unwrap_err().children().len(), 2);
+    }
 }
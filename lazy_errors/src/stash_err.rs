@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use crate::{OrStash, StashedResult};
+use crate::{stash::ErrorSink, OrStash, StashedResult};
 
 /// Adds the [`stash_err`](Self::stash_err) method on
 /// [`Iterator<Item = Result<T, E>>`](Iterator)
@@ -84,6 +84,11 @@ where
     /// If you want to map elements of a fixed-size array,
     /// take a look at [`try_map_or_stash`].
     ///
+    /// If the stash has a [`FailFast`] policy,
+    /// the iterator ends as soon as the limit of that policy
+    /// has been reached.
+    ///
+    /// [`FailFast`]: crate::FailFast
     /// [`stash_err`]: Self::stash_err
     /// [`try_collect_or_stash`]:
     /// crate::TryCollectOrStash::try_collect_or_stash
@@ -129,13 +134,18 @@ where
     Iter: Iterator<Item = Result<T, E>>,
     Iter::Item: OrStash<S, I, T>,
     E: Into<I>,
+    S: ErrorSink<E, I>,
 {
     type Item = T;
 
     /// Moves all `Err` items of the underlying iterator into the error stash
     /// until an `Ok` value is encountered.
     /// As soon as `Ok(T)` is encountered, `Some(T)` will be returned.
-    /// Returns `None` when the underlying iterator returns `None`.
+    /// Returns `None` when the underlying iterator returns `None`
+    /// or once the limit of the [`FailFast`] policy
+    /// of the stash has been reached.
+    ///
+    /// [`FailFast`]: crate::FailFast
     fn next(&mut self) -> Option<Self::Item> {
         // This method has no `#[track_caller]` annotation.
         // Thus, the backtrace will show the name of this file and
@@ -146,10 +156,15 @@ where
         // the backtrace would point to internals of the Rust standard library
         // instead of this file, making it even harder to understand.
         loop {
+            if self.stash.limit_reached().is_some() {
+                return None;
+            }
+
             match self.iter.next() {
                 Some(result) => match result.or_stash(self.stash) {
                     StashedResult::Err(_) => continue,
                     StashedResult::Ok(t) => return Some(t),
+                    StashedResult::LimitReached(_) => return None,
                 },
                 None => return None,
             };
@@ -24,11 +24,17 @@
 
 pub mod prelude;
 
-use core::fmt::{Debug, Display};
+use core::{
+    any::Any,
+    fmt::{Debug, Display},
+};
 
 use alloc::boxed::Box;
 
-use crate::{AdHocError, Error, ErrorData, StashedErrors, WrappedError};
+use crate::{
+    inner_error::downcast_error_tree_any, AdHocError, DowncastInner, Error,
+    ErrorData, InnerError, NodeKind, StashedErrors, WrappedError,
+};
 
 /// Marker trait for types that can be put into [`ErrorStash`]
 /// and other containers of this crate
@@ -87,6 +93,24 @@ use crate::{AdHocError, Error, ErrorData, StashedErrors, WrappedError};
 /// [`Stashable`]: prelude::Stashable
 pub trait Reportable: Display + Debug {}
 
+/// Extends [`Reportable`] by [`Any`],
+/// which allows `lazy_errors` to downcast errors at run-time.
+///
+/// This trait is implemented automatically for
+/// all `'static` types that implement [`Reportable`].
+/// It's only used by [`StashableAny`]; please take a look at
+/// its documentation for details.
+pub trait ReportableAny: Reportable + Any {
+    /// Returns `self` as `&dyn Any`.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<E: Reportable + Any> ReportableAny for E {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// Alias of the `Result<T, E>` we all know, but uses
 /// [`lazy_errors::surrogate_error_trait::prelude::Error`]
 /// as default value for `E` if not specified explitly.
@@ -143,6 +167,43 @@ surrogate_stash.push(regular_error);
 pub type Stashable<'a> =
     alloc::boxed::Box<dyn crate::Reportable + Send + Sync + 'a>;
 
+/// Alternative to [`Stashable`] that supports
+/// searching and downcasting errors at run-time,
+/// at the cost of requiring all errors to be `'static`.
+///
+/// [`Stashable`] is based on [`Reportable`],
+/// which only requires [`Display`] and [`Debug`].
+/// Thus, when you're using [`Stashable`] as
+/// [_inner error type_ `I`](crate::Error#inner-error-type-i),
+/// `lazy_errors` won't be able to look into boxed errors.
+/// For example, [`walk`](crate::ErrorData::walk) cannot descend into
+/// nested errors and [`find`](crate::ErrorData::find) is not available.
+/// If you need those features but `core::error::Error` is not available,
+/// you can opt into the [`Any`] bound by using `StashableAny` as `I`:
+///
+/// ```
+/// use lazy_errors::surrogate_error_trait::StashableAny;
+///
+/// type Error = lazy_errors::Error<StashableAny>;
+/// type ErrorStash<F, M> = lazy_errors::ErrorStash<F, M, StashableAny>;
+///
+/// let mut inner = ErrorStash::new(|| "Inner errors");
+/// inner.push(u8::try_from(256).unwrap_err());
+/// let inner: Error = inner.into_result().unwrap_err();
+///
+/// let mut errs = ErrorStash::new(|| "Outer errors");
+/// errs.push(inner);
+/// errs.push(u32::try_from(-1).unwrap_err());
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// assert_eq!(
+///     err.find_all::<core::num::TryFromIntError>()
+///         .count(),
+///     2
+/// );
+/// ```
+pub type StashableAny = Box<dyn ReportableAny + Send + Sync>;
+
 /// Makes all [`Reportable`]s implement
 /// `Into<Box<dyn Reportable>>`,
 /// so that they satisfy the `E: Into<I>` constraint used throughout this crate.
@@ -191,6 +252,128 @@ where
     }
 }
 
+/// Makes [`ReportableAny`]s implement
+/// `Into<Box<dyn ReportableAny>>` if possible,
+/// so that they satisfy the `E: Into<I>` constraint used throughout this crate.
+impl<E> From<E> for Box<dyn ReportableAny>
+where
+    E: ReportableAny,
+{
+    fn from(val: E) -> Self {
+        Box::new(val)
+    }
+}
+
+/// Makes [`ReportableAny`]s implement
+/// `Into<Box<dyn ReportableAny + Send>>` if possible,
+/// so that they satisfy the `E: Into<I>` constraint used throughout this crate.
+impl<E> From<E> for Box<dyn ReportableAny + Send>
+where
+    E: ReportableAny + Send,
+{
+    fn from(val: E) -> Self {
+        Box::new(val)
+    }
+}
+
+/// Makes [`ReportableAny`]s implement
+/// `Into<Box<dyn ReportableAny + Sync>>` if possible,
+/// so that they satisfy the `E: Into<I>` constraint used throughout this crate.
+impl<E> From<E> for Box<dyn ReportableAny + Sync>
+where
+    E: ReportableAny + Sync,
+{
+    fn from(val: E) -> Self {
+        Box::new(val)
+    }
+}
+
+/// Makes [`ReportableAny`]s implement
+/// `Into<Box<dyn ReportableAny + Send + Sync>>` if possible,
+/// so that they satisfy the `E: Into<I>` constraint used throughout this crate.
+impl<E> From<E> for Box<dyn ReportableAny + Send + Sync>
+where
+    E: ReportableAny + Send + Sync,
+{
+    fn from(val: E) -> Self {
+        Box::new(val)
+    }
+}
+
+/// [`Reportable`] is not related to `core::error::Error`.
+/// Thus, errors boxed as `dyn Reportable` are never returned from
+/// `core::error::Error::source`.
+impl InnerError for Box<dyn Reportable + '_> {}
+
+/// See `InnerError` implementation of `Box<dyn Reportable>`.
+impl InnerError for Box<dyn Reportable + Send + '_> {}
+
+/// See `InnerError` implementation of `Box<dyn Reportable>`.
+impl InnerError for Box<dyn Reportable + Sync + '_> {}
+
+/// See `InnerError` implementation of `Box<dyn Reportable>`.
+impl InnerError for Box<dyn Reportable + Send + Sync + '_> {}
+
+/// Allows [`walk`](crate::ErrorData::walk) to descend into nested errors.
+impl InnerError for Box<dyn ReportableAny> {
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree_any(self.as_ref().as_any())
+    }
+}
+
+/// See `InnerError` implementation of `Box<dyn ReportableAny>`.
+impl InnerError for Box<dyn ReportableAny + Send> {
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree_any(self.as_ref().as_any())
+    }
+}
+
+/// See `InnerError` implementation of `Box<dyn ReportableAny>`.
+impl InnerError for Box<dyn ReportableAny + Sync> {
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree_any(self.as_ref().as_any())
+    }
+}
+
+/// See `InnerError` implementation of `Box<dyn ReportableAny>`.
+impl InnerError for Box<dyn ReportableAny + Send + Sync> {
+    fn as_error_tree(&self) -> Option<NodeKind<'_, Self>> {
+        downcast_error_tree_any(self.as_ref().as_any())
+    }
+}
+
+impl<T: Any> DowncastInner<T> for Box<dyn ReportableAny> {
+    fn downcast_inner(&self) -> Option<&T> {
+        self.as_ref()
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+impl<T: Any> DowncastInner<T> for Box<dyn ReportableAny + Send> {
+    fn downcast_inner(&self) -> Option<&T> {
+        self.as_ref()
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+impl<T: Any> DowncastInner<T> for Box<dyn ReportableAny + Sync> {
+    fn downcast_inner(&self) -> Option<&T> {
+        self.as_ref()
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+impl<T: Any> DowncastInner<T> for Box<dyn ReportableAny + Send + Sync> {
+    fn downcast_inner(&self) -> Option<&T> {
+        self.as_ref()
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
 impl<I> Reportable for Error<I> where I: Display + Debug {}
 
 impl<I> Reportable for ErrorData<I> where I: Display + Debug {}
@@ -201,6 +384,8 @@ impl<I> Reportable for WrappedError<I> where I: Display + Debug {}
 
 impl Reportable for AdHocError {}
 
+impl Reportable for crate::OwnedLeaf {}
+
 impl Reportable for alloc::string::String {}
 
 impl Reportable for &str {}
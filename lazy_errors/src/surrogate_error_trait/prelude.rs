@@ -47,8 +47,8 @@
 //! [CUSTOM]: crate#example-custom-error-types
 
 pub use crate::{
-    err, try2, OrCreateStash, OrStash, OrWrap, OrWrapWith, StashErr,
-    TryCollectOrStash, TryMapOrStash,
+    err, try2, Attach, OrCreateStash, OrStash, OrWrap, OrWrapWith, StashErr,
+    TryCollectOrStash, TryMapOrStash, WithKind,
 };
 
 /// Type alias for [`crate::StashedResult`]
@@ -0,0 +1,186 @@
+use core::fmt::{self, Display};
+
+use tracing::field;
+use tracing_error::{SpanTrace, SpanTraceStatus};
+
+use crate::{ErrorData, InnerError, Location, Severity};
+
+/// Prints an error pretty-printed (`{:#}`) when printed via [`Display`].
+struct Pretty<'a, I>(&'a ErrorData<I>);
+
+/// Emits an event for an error that is added to a stash
+/// with the given `summary`.
+///
+/// Used as hook that is set by `with_tracing`.
+pub(crate) fn trace_push<I: Display>(
+    summary: &str,
+    error: &I,
+    location: Location,
+    severity: Severity,
+) {
+    let location = field::display(location);
+
+    match severity {
+        Severity::Warning => {
+            tracing::warn!(target: "lazy_errors", summary, location, "{error}")
+        }
+        Severity::Error => {
+            tracing::error!(target: "lazy_errors", summary, location, "{error}")
+        }
+    }
+}
+
+pub(crate) fn record_in_span<I>(error: &ErrorData<I>, span: &tracing::Span)
+where
+    I: Display + InnerError,
+{
+    let leaves = error.diagnostics().count() as u64;
+
+    span.record("error", field::display(error));
+    span.record("error.tree", as_value(error));
+    span.record("error.leaves", leaves);
+}
+
+pub(crate) fn as_value<I: Display>(
+    error: &ErrorData<I>,
+) -> impl tracing::Value + '_ {
+    field::display(Pretty(error))
+}
+
+/// Prints the span trace, unless it has not been captured.
+pub(crate) fn display_span_trace<W: fmt::Write + ?Sized>(
+    f: &mut W,
+    indent: &str,
+    span_trace: &SpanTrace,
+) -> fmt::Result {
+    if span_trace.status() != SpanTraceStatus::CAPTURED {
+        return Ok(());
+    }
+
+    writeln!(f)?;
+    write!(f, "{indent}span trace:")?;
+    for line in span_trace.to_string().lines() {
+        writeln!(f)?;
+        write!(f, "{indent}{line}")?;
+    }
+    Ok(())
+}
+
+impl<I: Display> Display for Pretty<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+    use std::{
+        format,
+        string::{String, ToString},
+        sync::{Arc, Mutex},
+        vec::Vec,
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::prelude::*;
+
+    /// Records the fields of all events and spans as `name=value` strings.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Recorder {
+        fn take(&self) -> Vec<String> {
+            core::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let mut records = self.0.lock().unwrap();
+            records.push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &span::Id, values: &span::Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let level = event.metadata().level().to_string();
+            self.0.lock().unwrap().push(level);
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn push_emits_event_if_enabled() {
+        let recorder = Recorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("Silent error");
+        assert!(recorder.take().is_empty());
+
+        let mut errs = ErrorStash::new(|| "Summary").with_tracing();
+        errs.push("First error");
+        let errs = errs.push_warning(Error::from_message("Second error"));
+
+        let locations = errs.locations();
+        assert_eq!(locations.len(), 2);
+        let (first, second) = (&locations[0], &locations[1]);
+
+        assert_eq!(recorder.take(), [
+            "ERROR".to_string(),
+            "message=First error".to_string(),
+            "summary=\"Summary\"".to_string(),
+            format!("location={first}"),
+            "WARN".to_string(),
+            "message=Second error".to_string(),
+            "summary=\"Summary\"".to_string(),
+            format!("location={second}"),
+        ]);
+    }
+
+    #[test]
+    fn record_in_span_records_fields() {
+        let recorder = Recorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let span = tracing::info_span!(
+            "span",
+            error = tracing::field::Empty,
+            error.leaves = tracing::field::Empty,
+        );
+
+        let mut errs = ErrorStash::new(|| "Summary");
+        errs.push("First error");
+        errs.push("Second error");
+        let err: Error = errs.into_result().unwrap_err();
+
+        err.record_in_span(&span);
+        assert_eq!(recorder.take(), [
+            "error=Summary (2 errors)",
+            "error.leaves=2",
+        ]);
+    }
+}
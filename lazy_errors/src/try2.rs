@@ -67,7 +67,8 @@ macro_rules! try2 {
     ($expr:expr $(,)?) => {
         match $expr {
             $crate::StashedResult::Ok(val) => val,
-            $crate::StashedResult::Err(errs) => {
+            $crate::StashedResult::Err(errs)
+            | $crate::StashedResult::LimitReached(errs) => {
                 return core::result::Result::Err(errs.take().into());
             }
         }
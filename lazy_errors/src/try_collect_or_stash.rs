@@ -1,5 +1,5 @@
 use crate::{
-    stash::{EnforceErrors, ErrorSource},
+    stash::{EnforceErrors, ErrorSink, ErrorSource},
     Error, OrStash, OrWrap, StashErr, StashedResult,
 };
 
@@ -46,6 +46,11 @@ where
     /// this method will return a [`StashedResult::Err`]
     /// containing that error stash instead.
     ///
+    /// If the stash has a [`FailFast`] policy,
+    /// this method stops evaluating items as soon as the limit
+    /// of that policy has been reached
+    /// and returns [`StashedResult::LimitReached`] instead.
+    ///
     /// ```
     /// # use core::str::FromStr;
     /// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
@@ -122,6 +127,7 @@ where
     /// take a look at [`try_map_or_stash`].
     ///
     /// [`ErrorStash::ok`]: crate::ErrorStash::ok
+    /// [`FailFast`]: crate::FailFast
     /// [`try_map_or_stash`]: crate::TryMapOrStash::try_map_or_stash
     fn try_collect_or_stash<C>(self, stash: &mut S) -> StashedResult<C, I>
     where
@@ -133,6 +139,7 @@ where
     Iter: Iterator<Item = Result<T, E>>,
     E: Into<I>,
     S: ErrorSource<I>,
+    S: ErrorSink<Error<I>, I>,
     S: EnforceErrors<I>,
     Error<I>: Into<I>,
     Result<T, Error<I>>: OrStash<S, I, T>,
@@ -147,21 +154,22 @@ where
         C: FromIterator<T>,
         Self: Sized,
     {
-        let before = stash.errors().len();
+        let before = stash.added_count();
 
         // Show this method in backtrace even despite `stash_err`
         // not supporting backtraces properly.
         let iter = self.map(|r| r.or_wrap());
         let result = iter.stash_err(stash).collect();
 
-        let after = stash.errors().len();
+        let after = stash.added_count();
+        let is_limit_reached = stash.limit_reached().is_some();
 
-        if before == after {
+        if before == after && !is_limit_reached {
             StashedResult::Ok(result)
         } else {
             // The stash "cannot" be empty now... unless in case of
             // weird `std::mem::take` shenanigans or API violations.
-            StashedResult::Err(stash.enforce_errors())
+            StashedResult::failed(stash.enforce_errors())
         }
     }
 }
@@ -189,13 +197,14 @@ mod tests {
         let errs: &mut StashWithErrors = match errs.ok() {
             crate::StashedResult::Ok(_) => unreachable!(),
             crate::StashedResult::Err(stash_with_errors) => stash_with_errors,
+            crate::StashedResult::LimitReached(_) => unreachable!(),
         };
 
         let empty: Vec<Result<u8>> = vec![];
         let empty: Vec<u8> = try2!(empty
             .into_iter()
             .try_collect_or_stash(errs));
-        assert_eq!(empty, &[]);
+        assert_eq!(empty, &[] as &[u8]);
 
         let ok: Vec<Result<u8>> = vec![Ok(42)];
         let ok: Vec<u8> = try2!(ok
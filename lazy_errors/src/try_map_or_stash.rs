@@ -50,6 +50,11 @@ where
     /// Otherwise, this method will return a [`StashedResult::Ok`]
     /// containing an array of the mapped elements, in order.
     ///
+    /// If the stash has a [`FailFast`] policy,
+    /// this method stops mapping elements as soon as the limit
+    /// of that policy has been reached
+    /// and returns [`StashedResult::LimitReached`] instead.
+    ///
     /// Here's an example using `[T; _]`:
     ///
     /// ```
@@ -151,6 +156,7 @@ where
     /// take a look at [`try_collect_or_stash`] and [`stash_err`].
     ///
     /// [`ErrorStash::ok`]: crate::ErrorStash::ok
+    /// [`FailFast`]: crate::FailFast
     /// [`stash_err`]: crate::StashErr::stash_err
     /// [`try_collect_or_stash`]:
     /// crate::TryCollectOrStash::try_collect_or_stash
@@ -188,7 +194,7 @@ where
         if vec.len() != N {
             // The stash "cannot" be empty now... unless in case of
             // weird `std::mem::take` shenanigans or API violations.
-            return StashedResult::Err(stash.enforce_errors());
+            return StashedResult::failed(stash.enforce_errors());
         }
 
         vec_try_into_or_stash(vec, stash)
@@ -223,7 +229,7 @@ where
         if vec.len() != N {
             // The stash "cannot" be empty now... unless in case of
             // weird `std::mem::take` shenanigans or API violations.
-            return StashedResult::Err(stash.enforce_errors());
+            return StashedResult::failed(stash.enforce_errors());
         }
 
         vec_try_into_or_stash(vec, stash)
@@ -241,11 +247,22 @@ fn filter_map_or_stash<T, F, U, E, S, I, const N: usize>(
 where
     F: FnMut(T) -> Result<U, E>,
     Result<U, E>: OrStash<S, I, U>,
+    E: Into<I>,
+    S: ErrorSink<E, I>,
 {
-    array
-        .into_iter()
-        .filter_map(|t| f(t).or_stash(stash).ok())
-        .collect()
+    let mut vec = Vec::with_capacity(N);
+    for t in array {
+        if stash.limit_reached().is_some() {
+            break;
+        }
+
+        match f(t).or_stash(stash) {
+            StashedResult::Ok(u) => vec.push(u),
+            StashedResult::Err(_) => continue,
+            StashedResult::LimitReached(_) => break,
+        }
+    }
+    vec
 }
 
 // Note that the `#[track_caller]` annotation on this method does not work
@@ -262,16 +279,24 @@ where
     Result<U, E2>: OrStash<S, I, U>,
     S: ErrorSink<E1, I>,
 {
-    array
-        .into_iter()
-        .filter_map(|r| match r {
-            Ok(t) => f(t).or_stash(stash).ok(),
-            Err(e) => {
-                stash.stash(e);
-                None
-            }
-        })
-        .collect()
+    let mut vec = Vec::with_capacity(N);
+    for r in array {
+        if ErrorSink::<E1, I>::limit_reached(stash).is_some() {
+            break;
+        }
+
+        let result = match r {
+            Ok(t) => f(t).or_stash(stash),
+            Err(e) => StashedResult::failed(stash.stash(e)),
+        };
+
+        match result {
+            StashedResult::Ok(u) => vec.push(u),
+            StashedResult::Err(_) => continue,
+            StashedResult::LimitReached(_) => break,
+        }
+    }
+    vec
 }
 
 fn vec_try_into_or_stash<T, S, I, const N: usize>(
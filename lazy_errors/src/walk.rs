@@ -0,0 +1,396 @@
+use core::any::Any;
+
+use alloc::vec::Vec;
+
+use crate::{
+    kind::downcast_kind, AdHocError, DowncastInner, ErrorData, ErrorKind,
+    ErrorLocation, InnerError, StashedErrors, WrappedError,
+};
+
+/// Iterator that walks the entire tree of an [`Error`],
+/// visiting each node depth-first (pre-order).
+///
+/// Values of this type are created by [`ErrorData::walk`],
+/// which you can call on [`Error`] as well.
+/// The iterator yields the error itself first, followed by its children,
+/// their children, and so on.
+///
+/// The iterator descends into those children that are
+/// errors from this crate themselves (for example, an [`Error`] that was
+/// pushed into an [`ErrorStash`] before being boxed as inner error).
+/// Such children are detected by [`InnerError::as_error_tree`].
+/// All other children are yielded as [`NodeKind::Leaf`].
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// use lazy_errors::prelude::*;
+///
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// use lazy_errors::surrogate_error_trait::prelude::*;
+///
+/// use lazy_errors::NodeKind;
+///
+/// let mut inner = ErrorStash::new(|| "Inner summary");
+/// inner.push("First error");
+/// inner.push(Error::from_message("Second error"));
+/// let inner: Error = inner.into_result().unwrap_err();
+///
+/// let mut errs = ErrorStash::new(|| "Outer summary");
+/// errs.push(Error::wrap_with(inner, "Context"));
+/// let err: Error = errs.into_result().unwrap_err();
+///
+/// let mut report = vec![];
+/// for node in err.walk() {
+///     let kind = match node.kind() {
+///         NodeKind::Stashed(_) => "stash",
+///         NodeKind::Wrapped(_) => "wrap",
+///         NodeKind::AdHoc(_) => "ad-hoc",
+///         NodeKind::Leaf(_) => "leaf",
+///     };
+///
+///     let location = match node.location() {
+///         Some(location) => format!("{location}"),
+///         None => String::from("-"),
+///     };
+///
+///     report.push(format!(
+///         "{}: {kind} {:?} at {location}",
+///         node.depth(),
+///         node.path(),
+///     ));
+/// }
+///
+/// let report = replace_line_numbers(&report.join("\n"));
+///
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// assert_eq!(report, indoc::indoc! {"
+///     0: stash [] at -
+///     1: wrap [0] at src/walk.rs:1234:56
+///     2: stash [0, 0] at src/walk.rs:1234:56
+///     3: leaf [0, 0, 0] at src/walk.rs:1234:56
+///     3: ad-hoc [0, 0, 1] at src/walk.rs:1234:56"});
+///
+/// // `Reportable` does not allow us to look into boxed errors:
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// assert_eq!(report, indoc::indoc! {"
+///     0: stash [] at -
+///     1: leaf [0] at src/walk.rs:1234:56"});
+/// ```
+///
+/// [`Error`]: crate::Error
+/// [`ErrorStash`]: crate::ErrorStash
+#[derive(Debug)]
+pub struct Walk<'a, I> {
+    stack: Vec<Node<'a, I>>,
+}
+
+/// A node in the tree of an [`Error`], as yielded by [`Walk`].
+///
+/// [`Error`]: crate::Error
+#[derive(Debug)]
+pub struct Node<'a, I> {
+    depth:    usize,
+    path:     Vec<usize>,
+    location: Option<&'a ErrorLocation>,
+    kind:     NodeKind<'a, I>,
+
+    /// The kind that was recorded by the parent for this node, if any.
+    entry_kind: Option<&'a dyn ErrorKind>,
+}
+
+/// The kind of a [`Node`], which also references the error
+/// that the node represents.
+#[derive(Debug)]
+pub enum NodeKind<'a, I> {
+    /// The node is a list of errors, usually created from an
+    /// [`ErrorStash`](crate::ErrorStash).
+    Stashed(&'a StashedErrors<I>),
+
+    /// The node wraps exactly one error.
+    Wrapped(&'a WrappedError<I>),
+
+    /// The node is an ad-hoc error, created from a message.
+    AdHoc(&'a AdHocError),
+
+    /// The node is an inner error that is not
+    /// (or could not be identified as) an error from this crate.
+    /// Leaves never have children.
+    Leaf(&'a I),
+}
+
+impl<'a, I> NodeKind<'a, I> {
+    fn own_location(&self) -> Option<&'a ErrorLocation> {
+        match self {
+            Self::Wrapped(err) => Some(err.location()),
+            Self::AdHoc(err) => Some(err.location()),
+            Self::Stashed(_) | Self::Leaf(_) => None,
+        }
+    }
+
+    fn own_error_kind(&self) -> Option<&'a dyn ErrorKind> {
+        match self {
+            Self::Stashed(errs) => errs.dyn_kind(),
+            Self::Wrapped(err) => err.dyn_kind(),
+            Self::AdHoc(err) => err.dyn_kind(),
+            Self::Leaf(_) => None,
+        }
+    }
+}
+
+impl<I> Clone for NodeKind<'_, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for NodeKind<'_, I> {}
+
+impl<'a, I> From<&'a ErrorData<I>> for NodeKind<'a, I> {
+    fn from(value: &'a ErrorData<I>) -> Self {
+        match value {
+            ErrorData::Stashed(errs) => Self::Stashed(errs),
+            ErrorData::Wrapped(err) => Self::Wrapped(err),
+            ErrorData::AdHoc(err) => Self::AdHoc(err),
+        }
+    }
+}
+
+impl<'a, I: InnerError> Walk<'a, I> {
+    pub(crate) fn new(root: &'a ErrorData<I>) -> Self {
+        let kind = NodeKind::from(root);
+        let root = Node {
+            depth: 0,
+            path: Vec::new(),
+            location: kind.own_location(),
+            kind,
+            entry_kind: None,
+        };
+
+        Self { stack: vec![root] }
+    }
+}
+
+impl<'a, I: InnerError> Iterator for Walk<'a, I> {
+    type Item = Node<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+
+        // Push in reverse order so that the first child is visited next.
+        match node.kind {
+            NodeKind::Stashed(errs) => {
+                let children = errs
+                    .errors()
+                    .iter()
+                    .zip(errs.locations());
+                for (i, (e, l)) in children.enumerate().rev() {
+                    let k = errs.entry_kind(i);
+                    self.stack.push(node.child(i, e, l, k));
+                }
+            }
+            NodeKind::Wrapped(err) => {
+                let l = err.location();
+                self.stack
+                    .push(node.child(0, err.inner(), l, None));
+            }
+            NodeKind::AdHoc(_) | NodeKind::Leaf(_) => (),
+        }
+
+        Some(node)
+    }
+}
+
+impl<'a, I> Node<'a, I> {
+    /// Returns the number of levels between this node and the root node.
+    /// The root node has a depth of zero.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the indices of the children that need to be followed,
+    /// starting at the root node, to reach this node.
+    /// The path of the root node is empty.
+    ///
+    /// The child of a [`WrappedError`] always has the index zero.
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Returns the kind of this node,
+    /// along with a reference to the error that it represents.
+    pub fn kind(&self) -> NodeKind<'a, I> {
+        self.kind
+    }
+
+    /// Returns the source location of this node, if known.
+    ///
+    /// [`WrappedError`]s and [`AdHocError`]s return the location
+    /// at which they were created.
+    /// All other nodes return the location
+    /// that was recorded by their parent when the error was added to it,
+    /// for example when the error was pushed into an
+    /// [`ErrorStash`](crate::ErrorStash).
+    /// The root node thus only has a location
+    /// if it is a [`WrappedError`] or an [`AdHocError`].
+    ///
+    /// If the error has been reconstructed from an
+    /// [`OwnedError`](crate::OwnedError), this is the original location.
+    pub fn location(&self) -> Option<&'a ErrorLocation> {
+        self.location
+    }
+
+    /// Returns the user-defined kind of this node if it is of type `K`.
+    ///
+    /// If this node is an error from this crate that has a kind,
+    /// that kind is returned. Otherwise, this method returns the kind
+    /// that was attached when the error was added to its parent,
+    /// for example via [`ErrorStash::push_with_kind`].
+    /// Please take a look at [`ErrorKind`] for details.
+    ///
+    /// [`ErrorStash::push_with_kind`]: crate::ErrorStash::push_with_kind
+    pub fn error_kind<K: ErrorKind>(&self) -> Option<&'a K> {
+        self.error_kinds()
+            .find_map(downcast_kind)
+    }
+
+    /// Returns the kind of this node (if any), followed by the kind
+    /// that was recorded by the parent for this node (if any).
+    pub(crate) fn error_kinds(
+        &self,
+    ) -> impl Iterator<Item = &'a dyn ErrorKind> {
+        self.kind
+            .own_error_kind()
+            .into_iter()
+            .chain(self.entry_kind)
+    }
+
+    /// Returns the error represented by this node as `T`
+    /// if it is a value of type `T`.
+    ///
+    /// Leaves are downcast via [`DowncastInner`].
+    /// All other nodes are errors from this crate,
+    /// such as [`StashedErrors`] or [`AdHocError`].
+    pub fn downcast_ref<T>(&self) -> Option<&'a T>
+    where
+        T: 'static,
+        I: DowncastInner<T> + 'static,
+    {
+        match self.kind {
+            NodeKind::Stashed(err) => (err as &dyn Any).downcast_ref(),
+            NodeKind::Wrapped(err) => (err as &dyn Any).downcast_ref(),
+            NodeKind::AdHoc(err) => (err as &dyn Any).downcast_ref(),
+            NodeKind::Leaf(err) => err.downcast_inner(),
+        }
+    }
+
+    fn child(
+        &self,
+        index: usize,
+        err: &'a I,
+        parent_loc: &'a ErrorLocation,
+        parent_kind: Option<&'a dyn ErrorKind>,
+    ) -> Self
+    where
+        I: InnerError,
+    {
+        let kind = err
+            .as_error_tree()
+            .unwrap_or(NodeKind::Leaf(err));
+        let location = kind
+            .own_location()
+            .unwrap_or(parent_loc);
+
+        let mut path = self.path.clone();
+        path.push(index);
+
+        Self {
+            depth: self.depth + 1,
+            path,
+            location: Some(location),
+            kind,
+            entry_kind: parent_kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    use crate::prelude::*;
+
+    #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+    use crate::surrogate_error_trait::prelude::*;
+
+    use super::NodeKind;
+
+    #[test]
+    fn walk_ad_hoc_error_yields_root_only() {
+        let err: Error = Error::from_message("Ad-hoc error");
+        let nodes: Vec<_> = err.walk().collect();
+
+        let node = match nodes.as_slice() {
+            [node] => node,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(node.depth(), 0);
+        assert!(node.path().is_empty());
+
+        let ad_hoc = match node.kind() {
+            NodeKind::AdHoc(err) => err,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(node.location(), Some(ad_hoc.location()));
+    }
+
+    #[test]
+    fn walk_wrapped_error_yields_inner_error_as_leaf() {
+        let err: Error = Error::wrap("Inner error");
+        let nodes: Vec<_> = err.walk().collect();
+
+        let [root, leaf] = match nodes.as_slice() {
+            [root, leaf] => [root, leaf],
+            _ => unreachable!(),
+        };
+
+        let wrapped = match root.kind() {
+            NodeKind::Wrapped(err) => err,
+            _ => unreachable!(),
+        };
+
+        let inner = match leaf.kind() {
+            NodeKind::Leaf(err) => err,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(inner.to_string(), "Inner error");
+        assert_eq!(leaf.depth(), 1);
+        assert_eq!(leaf.path(), &[0]);
+        assert_eq!(leaf.location(), Some(wrapped.location()));
+        assert_eq!(root.location(), Some(wrapped.location()));
+    }
+
+    #[test]
+    #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+    fn walk_visits_nested_children_before_siblings() {
+        let mut inner = ErrorStash::new(|| "Inner");
+        inner.push("A");
+        inner.push("B");
+
+        let mut errs = ErrorStash::new(|| "Outer");
+        errs.push(inner.into_result().unwrap_err());
+        errs.push("C");
+        let err: Error = errs.into_result().unwrap_err();
+
+        let paths: Vec<_> = err
+            .walk()
+            .map(|node| node.path().to_vec())
+            .collect();
+
+        assert_eq!(paths, [[].as_slice(), &[0], &[0, 0], &[0, 1], &[1]]);
+    }
+}
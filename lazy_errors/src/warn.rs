@@ -0,0 +1,74 @@
+/// Creates an ad-hoc [`Error`](crate::Error)
+/// of [`Severity::Warning`] from some message or format string.
+///
+/// This macro is the counterpart of [`err!`](crate::err!) for warnings.
+/// The [`AdHocError`] it creates reports its severity via
+/// [`AdHocError::severity`] and is reported as warning by
+/// [`Diagnostics`](crate::Diagnostics).
+/// Since stashes can hold inner errors of any type,
+/// whether an error makes the stash fail is decided when it is stashed:
+/// Pass the result of this macro to
+/// [`push_warning`](crate::ErrorStash::push_warning)
+/// or return it from a function whose result is stashed via
+/// [`or_stash_warning`](crate::OrStash::or_stash_warning).
+/// Warnings are printed when the stash is printed,
+/// but they do not cause [`ErrorStash::into_result`] to fail.
+/// Please take a look at [`Severity`] for details.
+///
+/// ```
+/// # use lazy_errors::doctest_line_num_helper as replace_line_numbers;
+/// #[cfg(any(feature = "rust-v1.81", feature = "std"))]
+/// use lazy_errors::{prelude::*, warn, Result};
+///
+/// #[cfg(not(any(feature = "rust-v1.81", feature = "std")))]
+/// use lazy_errors::{
+///     surrogate_error_trait::{prelude::*, Result},
+///     warn,
+/// };
+///
+/// fn check_name(name: &str) -> Result<()> {
+///     if name.len() > 8 {
+///         return Err(warn!("Name is longer than 8 characters: '{name}'"));
+///     }
+///
+///     if name.to_lowercase() != name {
+///         return Err(warn!("Name is not lowercase: '{name}'"));
+///     }
+///
+///     Ok(())
+/// }
+///
+/// let mut errs = ErrorStash::new(|| "Invalid names");
+/// check_name("lazy_errors").or_stash_warning(&mut errs);
+/// check_name("Foo").or_stash_warning(&mut errs);
+/// check_name("foo").or_stash_warning(&mut errs);
+///
+/// let warnings: Error = errs
+///     .into_result_with_warnings()
+///     .unwrap()
+///     .unwrap();
+///
+/// let printed = format!("{warnings:#}");
+/// let printed = replace_line_numbers(&printed);
+/// assert_eq!(printed, indoc::indoc! {"
+///     Invalid names
+///     - warning: Name is longer than 8 characters: 'lazy_errors'
+///       at src/warn.rs:1234:56
+///       at src/warn.rs:1234:56
+///     - warning: Name is not lowercase: 'Foo'
+///       at src/warn.rs:1234:56
+///       at src/warn.rs:1234:56"});
+/// ```
+///
+/// [`AdHocError`]: crate::AdHocError
+/// [`AdHocError::severity`]: crate::AdHocError::severity
+/// [`ErrorStash::into_result`]: crate::ErrorStash::into_result
+/// [`Severity`]: crate::Severity
+/// [`Severity::Warning`]: crate::Severity::Warning
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {{
+        extern crate alloc;
+        $crate::Error::from_warning_message(alloc::format!($($arg)*))
+    }};
+}
@@ -723,7 +723,7 @@ fn as_feature_flags(
         ],
         Some(RustVersion::V1_69) => &[
             "--version-range=1.69..=1.69",
-            "--exclude-features=default,eyre",
+            "--exclude-features=default,color,eyre,log",
             "--features=rust-v1.69,rust-v1.66,rust-v1.64",
             "--ignore-unknown-features",
             "--exclude-features=rust-v1.81,rust-v1.77",
@@ -732,7 +732,7 @@ fn as_feature_flags(
         ],
         Some(RustVersion::V1_66) => &[
             "--version-range=1.66..=1.66",
-            "--exclude-features=default,eyre",
+            "--exclude-features=default,anyhow,color,eyre,log",
             "--features=rust-v1.66,rust-v1.64",
             "--ignore-unknown-features",
             "--exclude-features=rust-v1.81,rust-v1.77,rust-v1.69",
@@ -741,7 +741,8 @@ fn as_feature_flags(
         ],
         Some(RustVersion::V1_64) => &[
             "--version-range=1.64..=1.64",
-            "--exclude-features=default,eyre",
+            "--exclude-features=default,anyhow,backtrace,color,eyre,log,\
+             tracing",
             "--features=rust-v1.64",
             "--ignore-unknown-features",
             "--exclude-features=rust-v1.81,rust-v1.77,rust-v1.69,rust-v1.66",
@@ -750,7 +751,8 @@ fn as_feature_flags(
         ],
         Some(RustVersion::V1_61) => &[
             "--version-range=1.61..=1.61",
-            "--exclude-features=default,eyre",
+            "--exclude-features=default,anyhow,backtrace,color,eyre,log,\
+             tracing",
             "--exclude-features=rust-v1.81,rust-v1.77,rust-v1.69,rust-v1.66,\
              rust-v1.64",
             "--feature-powerset",